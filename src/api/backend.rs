@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::api::client::ApiClient;
+use crate::api::demo::demo_api;
+use crate::api::dry_run::DryRunApi;
+use crate::config::settings::Config;
+use crate::error::Result;
+use crate::models::member::MemberInfo;
+use crate::models::parking::ParkingPermitRequest;
+use crate::models::permit::ParkingPermit;
+use crate::models::product::Product;
+use crate::models::purchase::PurchaseResponse;
+use crate::models::sale::Sale;
+
+#[async_trait]
+pub trait StregApi: Send + Sync {
+    async fn fetch_products(&self) -> Result<HashMap<String, Product>>;
+
+    async fn fetch_named_products(&self) -> Result<HashMap<String, i32>>;
+
+    async fn fetch_member_id(&self, username: &str) -> Result<Option<i32>>;
+
+    async fn fetch_member_info(&self, member_id: i32) -> Result<MemberInfo>;
+
+    async fn fetch_latest_sales(&self, member_id: i32) -> Result<Vec<Sale>>;
+
+    async fn make_purchase(&self, member_id: i32, buystring: &str) -> Result<PurchaseResponse>;
+
+    async fn register_parking(&self, request: &ParkingPermitRequest) -> Result<ParkingPermit>;
+}
+
+pub fn create_backend(config: &Config) -> Result<Arc<dyn StregApi>> {
+    let backend: Arc<dyn StregApi> = if config.demo {
+        Arc::new(demo_api()?)
+    } else {
+        Arc::new(ApiClient::new(config)?)
+    };
+
+    if config.dry_run() {
+        return Ok(Arc::new(DryRunApi::new(
+            backend,
+            config.api_url(),
+            config.room_id(),
+        )));
+    }
+
+    Ok(backend)
+}
@@ -1,51 +1,124 @@
+use async_trait::async_trait;
 use reqwest::Client;
+use serde::de::DeserializeOwned;
 use serde_json::json;
 use std::collections::HashMap;
+use std::time::Duration;
 
+use crate::api::backend::StregApi;
 use crate::api::endpoints::{
     ACTIVE_PRODUCTS_ENDPOINT, MEMBER_ID_ENDPOINT, MEMBER_INFO_ENDPOINT, NAMED_PRODUCTS_ENDPOINT,
-    PURCHASE_ENDPOINT, SALES_ENDPOINT,
+    PARKING_PERMIT_URL, PURCHASE_ENDPOINT, SALES_ENDPOINT,
 };
 use crate::config::settings::Config;
 use crate::error::{AppError, Result};
 use crate::models::member::{MemberId, MemberInfo, SalesResponse};
+use crate::models::parking::ParkingPermitRequest;
+use crate::models::permit::{ParkingFailure, ParkingPermit};
 use crate::models::product::Product;
+use crate::models::purchase::PurchaseResponse;
 use crate::models::sale::Sale;
 use crate::utils::formatters::sanitize_html;
 
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+const RETRY_MAX_DELAY: Duration = Duration::from_secs(4);
+
 #[derive(Clone)]
 pub struct ApiClient {
     client: Client,
     api_url: String,
     room_id: u32,
+    retries: u32,
 }
 
 impl ApiClient {
-    pub fn new(config: &Config) -> Self {
-        Self {
-            client: Client::new(),
-            api_url: Config::get_api_url(),
-            room_id: config.room_id,
+    pub fn new(config: &Config) -> Result<Self> {
+        let client = Client::builder()
+            .connect_timeout(config.network.connect_timeout())
+            .read_timeout(config.network.read_timeout())
+            .build()?;
+
+        Ok(Self {
+            client,
+            api_url: config.api_url(),
+            room_id: config.room_id(),
+            retries: config.network.retries,
+        })
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, url: &str, context: &'static str) -> Result<T> {
+        let mut attempt = 0;
+
+        loop {
+            match self.try_get_json(url, context).await {
+                Err(e) if attempt < self.retries && e.is_transient() => {
+                    tokio::time::sleep(retry_delay(attempt)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
         }
     }
 
-    pub async fn fetch_products(&self) -> Result<HashMap<String, Product>> {
+    async fn try_get_json<T: DeserializeOwned>(
+        &self,
+        url: &str,
+        context: &'static str,
+    ) -> Result<T> {
+        let response = self
+            .client
+            .get(url)
+            .send()
+            .await
+            .map_err(|e| request_error(e, context))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(AppError::HttpStatus { context, status });
+        }
+
+        let body = response
+            .text()
+            .await
+            .map_err(|e| request_error(e, context))?;
+
+        serde_json::from_str(&body).map_err(|source| AppError::Decode { context, source })
+    }
+}
+
+fn retry_delay(attempt: u32) -> Duration {
+    RETRY_BASE_DELAY
+        .saturating_mul(2u32.saturating_pow(attempt))
+        .min(RETRY_MAX_DELAY)
+}
+
+fn request_error(err: reqwest::Error, context: &'static str) -> AppError {
+    if err.is_timeout() {
+        AppError::Timeout { context }
+    } else {
+        AppError::Network(err)
+    }
+}
+
+pub fn purchase_body(member_id: i32, buystring: &str, room_id: u32) -> serde_json::Value {
+    json!({
+        "member_id": member_id,
+        "buystring": buystring,
+        "room": room_id
+    })
+}
+
+#[async_trait]
+impl StregApi for ApiClient {
+    async fn fetch_products(&self) -> Result<HashMap<String, Product>> {
         let url = format!(
             "{}{}",
             self.api_url,
             ACTIVE_PRODUCTS_ENDPOINT.replace("{room_id}", &self.room_id.to_string())
         );
 
-        let response = self.client.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(AppError::Api(format!(
-                "Failed to fetch products: HTTP status {}",
-                response.status()
-            )));
-        }
-
-        let mut products: HashMap<String, Product> = response.json().await?;
+        let mut products: HashMap<String, Product> =
+            self.get_json(&url, "Failed to fetch products").await?;
 
         for (product_id, product) in products.iter_mut() {
             product.name = sanitize_html(&product.name);
@@ -55,135 +128,89 @@ impl ApiClient {
         Ok(products)
     }
 
-    pub async fn fetch_named_products(&self) -> Result<HashMap<String, i32>> {
+    async fn fetch_named_products(&self) -> Result<HashMap<String, i32>> {
         let url = format!("{}{}", self.api_url, NAMED_PRODUCTS_ENDPOINT);
 
-        let response = self.client.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(AppError::Api(format!(
-                "Failed to fetch named products: HTTP status {}",
-                response.status()
-            )));
-        }
-
-        let named_products: HashMap<String, i32> = response.json().await?;
-        Ok(named_products)
+        self.get_json(&url, "Failed to fetch named products").await
     }
 
-    pub async fn fetch_member_id(&self, username: &str) -> Result<Option<i32>> {
+    async fn fetch_member_id(&self, username: &str) -> Result<Option<i32>> {
         let url = format!(
             "{}{}",
             self.api_url,
             MEMBER_ID_ENDPOINT.replace("{username}", username)
         );
 
-        let response = self.client.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(AppError::Api(format!(
-                "Failed to fetch member ID: HTTP status {}",
-                response.status()
-            )));
-        }
-
-        let member_id_response: MemberId = response.json().await?;
+        let member_id_response: MemberId = self.get_json(&url, "Failed to fetch member ID").await?;
         Ok(Some(member_id_response.member_id))
     }
 
-    pub async fn fetch_member_info(&self, member_id: i32) -> Result<MemberInfo> {
+    async fn fetch_member_info(&self, member_id: i32) -> Result<MemberInfo> {
         let url = format!(
             "{}{}",
             self.api_url,
             MEMBER_INFO_ENDPOINT.replace("{member_id}", &member_id.to_string())
         );
 
-        let response = self.client.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(AppError::Api(format!(
-                "Failed to fetch member info: HTTP status {}",
-                response.status()
-            )));
-        }
-
-        let member_info: MemberInfo = response.json().await?;
-        Ok(member_info)
+        self.get_json(&url, "Failed to fetch member info").await
     }
 
-    pub async fn fetch_latest_sales(&self, member_id: i32) -> Result<Vec<Sale>> {
+    async fn fetch_latest_sales(&self, member_id: i32) -> Result<Vec<Sale>> {
         let url = format!(
             "{}{}",
             self.api_url,
             SALES_ENDPOINT.replace("{member_id}", &member_id.to_string())
         );
 
-        let response = self.client.get(&url).send().await?;
-
-        if !response.status().is_success() {
-            return Err(AppError::Api(format!(
-                "Failed to fetch sales: HTTP status {}",
-                response.status()
-            )));
-        }
-
-        let sales_response: SalesResponse = response.json().await?;
+        let sales_response: SalesResponse = self.get_json(&url, "Failed to fetch sales").await?;
         Ok(sales_response.sales)
     }
 
-    pub async fn make_purchase(&self, member_id: i32, buystring: &str) -> Result<()> {
+    async fn make_purchase(&self, member_id: i32, buystring: &str) -> Result<PurchaseResponse> {
         let url = format!("{}{}", self.api_url, PURCHASE_ENDPOINT);
 
-        let body = json!({
-            "member_id": member_id,
-            "buystring": buystring,
-            "room": self.room_id
-        });
+        let body = purchase_body(member_id, buystring, self.room_id);
 
-        let response = self.client.post(&url).json(&body).send().await?;
+        let response = self
+            .client
+            .post(&url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|e| request_error(e, "Failed to make purchase"))?;
 
         if !response.status().is_success() {
-            return Err(AppError::Api(format!(
-                "Failed to make purchase: HTTP status {}",
-                response.status()
-            )));
+            return Err(AppError::HttpStatus {
+                context: "Failed to make purchase",
+                status: response.status(),
+            });
         }
 
-        Ok(())
-    }
+        // The sale already went through, so an unexpected body must not become an error.
+        let body = response.text().await.unwrap_or_default();
 
-    pub async fn register_parking(&self, plate: &str, phone_number: &str) -> Result<()> {
-        let payload = json!({
-            "email": "",
-            "PhoneNumber": format!("45{}", phone_number),
-            "VehicleRegistrationCountry": "DK",
-            "Duration": 600,
-            "VehicleRegistration": plate,
-            "parkingAreas": [
-                {
-                    "ParkingAreaId": 1956,
-                    "ParkingAreaKey": "ADK-4688"
-                }
-            ],
-            "UId": "12cdf204-d969-469a-9bd5-c1f1fc59ee34",
-            "Lang": "da"
-        });
+        Ok(serde_json::from_str(&body).unwrap_or_default())
+    }
 
+    async fn register_parking(&self, request: &ParkingPermitRequest) -> Result<ParkingPermit> {
         let response = self
             .client
-            .post("https://api.mobile-parking.eu/v10/permit/Tablet/confirm")
-            .json(&payload)
+            .post(PARKING_PERMIT_URL)
+            .json(request)
             .send()
-            .await?;
+            .await
+            .map_err(|e| request_error(e, "Parking registration failed"))?;
 
-        if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().await.unwrap_or_default();
-            return Err(AppError::Api(format!(
-                "Parking registration failed: {status} - {error_text}"
-            )));
+        let status = response.status();
+        let body = response.text().await.unwrap_or_default();
+
+        if !status.is_success() {
+            return Err(AppError::Api(match ParkingFailure::from_response(&body) {
+                Some(failure) => failure.to_string(),
+                None => format!("Parking registration failed: {status} - {body}"),
+            }));
         }
 
-        Ok(())
+        ParkingPermit::from_response(&body).map_err(|failure| AppError::Api(failure.to_string()))
     }
 }
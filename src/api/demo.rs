@@ -0,0 +1,70 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use crate::api::fake::FakeApi;
+use crate::error::Result;
+use crate::models::sale::Sale;
+use crate::utils::money::Money;
+
+pub const DEMO_USERNAME: &str = "demo";
+
+const DEMO_FIXTURES: &str = include_str!("fixtures/demo.json");
+
+#[derive(Deserialize)]
+struct DemoFixtures {
+    member: DemoMember,
+    products: HashMap<String, DemoProduct>,
+    named_products: HashMap<String, i32>,
+    sales: Vec<DemoSale>,
+}
+
+#[derive(Deserialize)]
+struct DemoMember {
+    name: String,
+    balance: Money,
+}
+
+#[derive(Deserialize)]
+struct DemoProduct {
+    name: String,
+    price: Money,
+}
+
+#[derive(Deserialize)]
+struct DemoSale {
+    hours_ago: i64,
+    product: String,
+    price: Money,
+}
+
+pub fn demo_api() -> Result<FakeApi> {
+    let fixtures: DemoFixtures = serde_json::from_str(DEMO_FIXTURES)?;
+
+    let mut api = FakeApi::new().with_member(
+        DEMO_USERNAME,
+        &fixtures.member.name,
+        fixtures.member.balance,
+    );
+
+    for (id, product) in &fixtures.products {
+        api = api.with_product(id, &product.name, product.price);
+    }
+
+    for (name, product_id) in &fixtures.named_products {
+        api = api.with_named_product(name, *product_id);
+    }
+
+    let now = chrono::Local::now();
+    for sale in fixtures.sales {
+        api = api.with_sale(
+            DEMO_USERNAME,
+            Sale {
+                timestamp: (now - chrono::Duration::hours(sale.hours_ago)).to_rfc3339(),
+                product: sale.product,
+                price: sale.price,
+            },
+        );
+    }
+
+    Ok(api)
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::api::backend::StregApi;
+use crate::api::client::purchase_body;
+use crate::api::endpoints::{PARKING_PERMIT_URL, PURCHASE_ENDPOINT};
+use crate::error::{AppError, Result};
+use crate::models::member::MemberInfo;
+use crate::models::parking::ParkingPermitRequest;
+use crate::models::permit::ParkingPermit;
+use crate::models::product::Product;
+use crate::models::purchase::PurchaseResponse;
+use crate::models::sale::Sale;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DryRunRequest {
+    pub url: String,
+    pub body: Value,
+}
+
+impl DryRunRequest {
+    pub fn purchase(api_url: &str, member_id: i32, buystring: &str, room_id: u32) -> Self {
+        Self {
+            url: format!("{api_url}{PURCHASE_ENDPOINT}"),
+            body: purchase_body(member_id, buystring, room_id),
+        }
+    }
+
+    pub fn parking(request: &ParkingPermitRequest) -> Self {
+        Self {
+            url: PARKING_PERMIT_URL.to_string(),
+            body: serde_json::to_value(request).unwrap_or_default(),
+        }
+    }
+}
+
+impl fmt::Display for DryRunRequest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "POST {} {}", self.url, self.body)
+    }
+}
+
+pub struct DryRunApi {
+    inner: Arc<dyn StregApi>,
+    api_url: String,
+    room_id: u32,
+}
+
+impl DryRunApi {
+    pub fn new(inner: Arc<dyn StregApi>, api_url: String, room_id: u32) -> Self {
+        Self {
+            inner,
+            api_url,
+            room_id,
+        }
+    }
+}
+
+#[async_trait]
+impl StregApi for DryRunApi {
+    async fn fetch_products(&self) -> Result<HashMap<String, Product>> {
+        self.inner.fetch_products().await
+    }
+
+    async fn fetch_named_products(&self) -> Result<HashMap<String, i32>> {
+        self.inner.fetch_named_products().await
+    }
+
+    async fn fetch_member_id(&self, username: &str) -> Result<Option<i32>> {
+        self.inner.fetch_member_id(username).await
+    }
+
+    async fn fetch_member_info(&self, member_id: i32) -> Result<MemberInfo> {
+        self.inner.fetch_member_info(member_id).await
+    }
+
+    async fn fetch_latest_sales(&self, member_id: i32) -> Result<Vec<Sale>> {
+        self.inner.fetch_latest_sales(member_id).await
+    }
+
+    async fn make_purchase(&self, member_id: i32, buystring: &str) -> Result<PurchaseResponse> {
+        Err(AppError::DryRun(Box::new(DryRunRequest::purchase(
+            &self.api_url,
+            member_id,
+            buystring,
+            self.room_id,
+        ))))
+    }
+
+    async fn register_parking(&self, request: &ParkingPermitRequest) -> Result<ParkingPermit> {
+        Err(AppError::DryRun(Box::new(DryRunRequest::parking(request))))
+    }
+}
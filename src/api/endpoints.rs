@@ -4,3 +4,4 @@ pub const MEMBER_ID_ENDPOINT: &str = "/member/get_id?username={username}";
 pub const MEMBER_INFO_ENDPOINT: &str = "/member?member_id={member_id}";
 pub const SALES_ENDPOINT: &str = "/member/sales?member_id={member_id}";
 pub const PURCHASE_ENDPOINT: &str = "/sale";
+pub const PARKING_PERMIT_URL: &str = "https://api.mobile-parking.eu/v10/permit/Tablet/confirm";
@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use reqwest::StatusCode;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use crate::api::backend::StregApi;
+use crate::app::buystring::{parse_buy_items, resolved_total};
+use crate::error::{AppError, Result};
+use crate::models::member::MemberInfo;
+use crate::models::parking::ParkingPermitRequest;
+use crate::models::permit::ParkingPermit;
+use crate::models::product::Product;
+use crate::models::purchase::{PurchaseResponse, PurchaseValues};
+use crate::models::sale::Sale;
+use crate::utils::money::Money;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FakeEndpoint {
+    Products,
+    NamedProducts,
+    MemberId,
+    MemberInfo,
+    Sales,
+    Purchase,
+    Parking,
+}
+
+#[derive(Default)]
+pub struct FakeApi {
+    data: Mutex<FakeData>,
+}
+
+#[derive(Default)]
+struct FakeData {
+    products: HashMap<String, Product>,
+    named_products: HashMap<String, i32>,
+    members: Vec<FakeMember>,
+    failures: HashSet<FakeEndpoint>,
+    parking_registrations: Vec<ParkingPermitRequest>,
+}
+
+struct FakeMember {
+    id: i32,
+    info: MemberInfo,
+    sales: Vec<Sale>,
+}
+
+impl FakeApi {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_product(self, id: &str, name: &str, price: Money) -> Self {
+        self.lock().products.insert(
+            id.to_string(),
+            Product {
+                id: id.to_string(),
+                name: name.to_string(),
+                price,
+            },
+        );
+        self
+    }
+
+    pub fn with_named_product(self, name: &str, product_id: i32) -> Self {
+        self.lock()
+            .named_products
+            .insert(name.to_string(), product_id);
+        self
+    }
+
+    pub fn with_member(self, username: &str, name: &str, balance: Money) -> Self {
+        {
+            let mut data = self.lock();
+            let id = data.members.len() as i32 + 1;
+            data.members.push(FakeMember {
+                id,
+                info: MemberInfo {
+                    balance,
+                    username: username.to_string(),
+                    name: name.to_string(),
+                },
+                sales: Vec::new(),
+            });
+        }
+        self
+    }
+
+    pub fn with_sale(self, username: &str, sale: Sale) -> Self {
+        if let Some(member) = self
+            .lock()
+            .members
+            .iter_mut()
+            .find(|member| member.info.username == username)
+        {
+            member.sales.push(sale);
+        }
+        self
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, FakeData> {
+        self.data.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn check(data: &FakeData, endpoint: FakeEndpoint) -> Result<()> {
+        if data.failures.contains(&endpoint) {
+            return Err(AppError::Api(format!("Simulated failure for {endpoint:?}")));
+        }
+        Ok(())
+    }
+
+    fn resolve_product<'a>(data: &'a FakeData, token: &str) -> Option<&'a Product> {
+        data.products.get(token).or_else(|| {
+            data.named_products
+                .get(token)
+                .and_then(|id| data.products.get(&id.to_string()))
+        })
+    }
+}
+
+fn bad_request(context: &'static str) -> AppError {
+    AppError::HttpStatus {
+        context,
+        status: StatusCode::BAD_REQUEST,
+    }
+}
+
+#[cfg(test)]
+impl FakeApi {
+    pub fn failing(self, endpoint: FakeEndpoint) -> Self {
+        self.set_failing(endpoint, true);
+        self
+    }
+
+    pub fn set_failing(&self, endpoint: FakeEndpoint, failing: bool) {
+        let mut data = self.lock();
+        if failing {
+            data.failures.insert(endpoint);
+        } else {
+            data.failures.remove(&endpoint);
+        }
+    }
+
+    pub fn balance_of(&self, username: &str) -> Option<Money> {
+        self.lock()
+            .members
+            .iter()
+            .find(|member| member.info.username == username)
+            .map(|member| member.info.balance)
+    }
+
+    pub fn parking_registrations(&self) -> Vec<ParkingPermitRequest> {
+        self.lock().parking_registrations.clone()
+    }
+}
+
+#[async_trait]
+impl StregApi for FakeApi {
+    async fn fetch_products(&self) -> Result<HashMap<String, Product>> {
+        let data = self.lock();
+        Self::check(&data, FakeEndpoint::Products)?;
+        Ok(data.products.clone())
+    }
+
+    async fn fetch_named_products(&self) -> Result<HashMap<String, i32>> {
+        let data = self.lock();
+        Self::check(&data, FakeEndpoint::NamedProducts)?;
+        Ok(data.named_products.clone())
+    }
+
+    async fn fetch_member_id(&self, username: &str) -> Result<Option<i32>> {
+        let data = self.lock();
+        Self::check(&data, FakeEndpoint::MemberId)?;
+
+        data.members
+            .iter()
+            .find(|member| member.info.username == username)
+            .map(|member| Some(member.id))
+            .ok_or_else(|| bad_request("Failed to fetch member ID"))
+    }
+
+    async fn fetch_member_info(&self, member_id: i32) -> Result<MemberInfo> {
+        let data = self.lock();
+        Self::check(&data, FakeEndpoint::MemberInfo)?;
+
+        data.members
+            .iter()
+            .find(|member| member.id == member_id)
+            .map(|member| member.info.clone())
+            .ok_or_else(|| bad_request("Failed to fetch member info"))
+    }
+
+    async fn fetch_latest_sales(&self, member_id: i32) -> Result<Vec<Sale>> {
+        let data = self.lock();
+        Self::check(&data, FakeEndpoint::Sales)?;
+
+        data.members
+            .iter()
+            .find(|member| member.id == member_id)
+            .map(|member| member.sales.clone())
+            .ok_or_else(|| bad_request("Failed to fetch sales"))
+    }
+
+    async fn make_purchase(&self, member_id: i32, buystring: &str) -> Result<PurchaseResponse> {
+        let mut data = self.lock();
+        Self::check(&data, FakeEndpoint::Purchase)?;
+
+        let (_, items) = buystring
+            .split_once(' ')
+            .ok_or_else(|| AppError::Api("Failed to make purchase: empty buystring".to_string()))?;
+
+        let mut bought = Vec::new();
+        for item in parse_buy_items(items)? {
+            let product = Self::resolve_product(&data, &item.product_id).ok_or_else(|| {
+                AppError::Api(format!(
+                    "Failed to make purchase: unknown product {}",
+                    item.product_id
+                ))
+            })?;
+            bought.push((product.clone(), item.quantity));
+        }
+
+        let total = resolved_total(&bought)?;
+
+        let member = data
+            .members
+            .iter_mut()
+            .find(|member| member.id == member_id)
+            .ok_or_else(|| AppError::Api("Failed to make purchase: unknown member".to_string()))?;
+
+        if member.info.balance < total {
+            return Err(AppError::Api(
+                "Failed to make purchase: insufficient balance".to_string(),
+            ));
+        }
+
+        member.info.balance = member.info.balance - total;
+
+        let timestamp = chrono::Local::now().to_rfc3339();
+        for (product, quantity) in bought {
+            for _ in 0..quantity {
+                member.sales.insert(
+                    0,
+                    Sale {
+                        timestamp: timestamp.clone(),
+                        product: product.name.clone(),
+                        price: product.price,
+                    },
+                );
+            }
+        }
+
+        Ok(PurchaseResponse {
+            values: Some(PurchaseValues {
+                cost: Some(total),
+                member_balance: Some(member.info.balance),
+                member_has_low_balance: Some(member.info.balance < Money::new(5000)),
+                ..PurchaseValues::default()
+            }),
+        })
+    }
+
+    async fn register_parking(&self, request: &ParkingPermitRequest) -> Result<ParkingPermit> {
+        let mut data = self.lock();
+        Self::check(&data, FakeEndpoint::Parking)?;
+
+        data.parking_registrations.push(request.clone());
+
+        let now = Local::now();
+        Ok(ParkingPermit {
+            id: Some(format!("DEMO-{}", data.parking_registrations.len())),
+            valid_from: Some(now),
+            valid_to: Some(now + Duration::minutes(i64::from(request.duration))),
+            zone: request
+                .parking_areas
+                .first()
+                .map(|area| area.parking_area_key.clone()),
+            unrecognized_response: None,
+        })
+    }
+}
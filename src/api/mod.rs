@@ -1,2 +1,6 @@
+pub mod backend;
 pub mod client;
+pub mod demo;
+pub mod dry_run;
 pub mod endpoints;
+pub mod fake;
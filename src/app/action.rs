@@ -0,0 +1,116 @@
+use crate::app::state::HistoryField;
+use crate::app::stats::StatsPeriod;
+use crate::app::tasks::{PurchaseTarget, TaskResult};
+
+pub enum Action {
+    Start,
+    Quit,
+    Tick,
+    Resize { width: u16, height: u16 },
+    TaskCompleted(TaskResult),
+    ConfigSaveFailed(String),
+    ParkingLogFailed(String),
+    LedgerFailed(String),
+    CancelLoading,
+    Refresh,
+
+    MoveDown,
+    MoveUp,
+    PressG,
+    GoToBottom,
+    PushCountDigit(char),
+    AddSelectedToCart,
+    ToggleFavorite,
+    NextFavorite,
+    OpenPurchase,
+    OpenUsername,
+    OpenSearch,
+    OpenParking,
+    OpenParkingLog,
+    OpenCart,
+    OpenQuickBuy,
+    OpenHistory,
+    OpenStats,
+
+    LoginInput(char),
+    LoginBackspace,
+    SubmitLogin,
+    CancelLogin,
+
+    UsernameInput(char),
+    UsernameBackspace,
+    SubmitUsername,
+    CloseUsername,
+
+    IncreaseQuantity,
+    DecreaseQuantity,
+    ConfirmPurchase,
+    ClosePurchase,
+    CancelPurchase(PurchaseTarget),
+
+    NextCartLine,
+    PreviousCartLine,
+    IncreaseCartQuantity,
+    DecreaseCartQuantity,
+    RemoveCartLine,
+    BuyCart,
+    CloseCart,
+
+    QuickBuyInput(char),
+    QuickBuyBackspace,
+    ReviewQuickBuy,
+    EditQuickBuy,
+    ConfirmQuickBuy,
+    CloseQuickBuy,
+
+    SearchInput(char),
+    SearchBackspace,
+    NextSearchResult,
+    PreviousSearchResult,
+    SelectSearchResult,
+    AddSearchResultToCart,
+    CloseSearch,
+
+    ParkingInput(char),
+    ParkingBackspace,
+    NextParkingField,
+    PreviousParkingField,
+    NextParkingProfile,
+    PreviousParkingProfile,
+    NextParkingEntry,
+    PreviousParkingEntry,
+    AddParkingEntry,
+    EditParkingEntry,
+    DeleteParkingEntry,
+    SaveParkingEntry,
+    CancelParkingEdit,
+    SubmitParking,
+    ConfirmParking,
+    CancelParking,
+    CloseParking,
+
+    NextParkingLogEntry,
+    PreviousParkingLogEntry,
+    CloseParkingLog,
+
+    ScrollHistoryDown,
+    ScrollHistoryUp,
+    PageHistoryDown,
+    PageHistoryUp,
+    HistoryTop,
+    HistoryBottom,
+    EditHistoryFilter(HistoryField),
+    NextHistoryField,
+    HistoryInput(char),
+    HistoryBackspace,
+    FinishHistoryEdit,
+    ClearHistoryFilters,
+    CloseHistory,
+
+    NextStatsPeriod,
+    PreviousStatsPeriod,
+    SetStatsPeriod(StatsPeriod),
+    CloseStats,
+
+    CloseErrorModal,
+}
@@ -0,0 +1,112 @@
+use std::collections::HashMap;
+
+use crate::error::{AppError, Result};
+use crate::models::product::Product;
+use crate::utils::money::Money;
+
+pub const MAX_QUANTITY: u32 = 99;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuyItem {
+    pub product_id: String,
+    pub quantity: u32,
+}
+
+pub fn parse_buy_items(input: &str) -> Result<Vec<BuyItem>> {
+    let mut items = Vec::new();
+
+    for token in input.split_whitespace() {
+        let (product_id, quantity) = match token.split_once(':') {
+            Some((id, qty)) => {
+                let quantity = qty.parse::<u32>().map_err(|_| {
+                    AppError::Input(format!("Invalid quantity '{qty}' in '{token}'"))
+                })?;
+                (id, quantity)
+            }
+            None => (token, 1),
+        };
+
+        if product_id.is_empty() || !product_id.chars().all(|c| c.is_alphanumeric()) {
+            return Err(AppError::Input(format!("Invalid product '{product_id}'")));
+        }
+
+        if quantity == 0 {
+            return Err(AppError::Input(format!(
+                "Quantity for product {product_id} must be at least 1"
+            )));
+        }
+
+        if quantity > MAX_QUANTITY {
+            return Err(AppError::Input(format!(
+                "Quantity for product {product_id} can be at most {MAX_QUANTITY}"
+            )));
+        }
+
+        items.push(BuyItem {
+            product_id: product_id.to_string(),
+            quantity,
+        });
+    }
+
+    if items.is_empty() {
+        return Err(AppError::Input("Nothing to buy".to_string()));
+    }
+
+    Ok(items)
+}
+
+pub fn format_buystring(username: &str, items: &[BuyItem]) -> String {
+    let mut buystring = username.to_string();
+
+    for item in items {
+        buystring.push_str(&format!(" {}:{}", item.product_id, item.quantity));
+    }
+
+    buystring
+}
+
+pub fn resolve_buy_items(
+    items: &[BuyItem],
+    products: &HashMap<String, Product>,
+    named_products: &HashMap<String, i32>,
+) -> Result<Vec<(Product, u32)>> {
+    items
+        .iter()
+        .map(|item| {
+            let product = products
+                .get(&item.product_id)
+                .or_else(|| {
+                    named_products
+                        .get(&item.product_id.to_lowercase())
+                        .and_then(|id| products.get(&id.to_string()))
+                })
+                .ok_or_else(|| {
+                    AppError::Input(format!("Product {} is not available", item.product_id))
+                })?;
+
+            Ok((product.clone(), item.quantity))
+        })
+        .collect()
+}
+
+pub fn resolved_items(lines: &[(Product, u32)]) -> Vec<BuyItem> {
+    lines
+        .iter()
+        .map(|(product, quantity)| BuyItem {
+            product_id: product.id.clone(),
+            quantity: *quantity,
+        })
+        .collect()
+}
+
+pub fn resolved_total(lines: &[(Product, u32)]) -> Result<Money> {
+    lines
+        .iter()
+        .try_fold(Money::new(0), |total, (product, quantity)| {
+            product
+                .price
+                .checked_mul(*quantity)
+                .and_then(|line_total| total.checked_add(line_total))
+        })
+        .ok_or_else(|| AppError::Input("The purchase total is too large".to_string()))
+}
@@ -0,0 +1,59 @@
+use crate::app::state::AppState;
+use crate::config::cache::{CachedMember, CachedProducts, DataCache};
+
+impl AppState {
+    pub fn restore_cache(&mut self, cache: DataCache) {
+        if let Some(products) = cache.products {
+            self.products.items = products.items;
+            self.products.named_products = products.named_products;
+            self.products.updated_at = Some(products.fetched_at);
+            self.products.stale = true;
+        }
+
+        let Some(member) = cache.member else {
+            return;
+        };
+
+        if self.config.username() == Some(&member.username) {
+            self.user.member_id = Some(member.member_id);
+            self.user.member_info = Some(member.info);
+            self.user.latest_sales = member.latest_sales;
+            self.user.updated_at = Some(member.fetched_at);
+            self.user.stale = true;
+        }
+    }
+
+    pub fn data_cache(&self) -> DataCache {
+        let products = self
+            .products
+            .updated_at
+            .filter(|_| !self.products.items.is_empty())
+            .map(|fetched_at| CachedProducts {
+                fetched_at,
+                items: self.products.items.clone(),
+                named_products: self.products.named_products.clone(),
+            });
+
+        let member = match (
+            self.config.username(),
+            self.user.member_id,
+            &self.user.member_info,
+            self.user.updated_at,
+        ) {
+            (Some(username), Some(member_id), Some(info), Some(fetched_at)) => Some(CachedMember {
+                fetched_at,
+                username: username.clone(),
+                member_id,
+                info: info.clone(),
+                latest_sales: self.user.latest_sales.clone(),
+            }),
+            _ => None,
+        };
+
+        DataCache {
+            products,
+            member,
+            ..DataCache::new(&self.config)
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use crate::api::dry_run::DryRunRequest;
+use crate::app::buystring::{format_buystring, BuyItem};
+use crate::app::modals::cart::CartModalActions;
+use crate::app::modals::parking::ParkingModalActions;
+use crate::app::modals::quick_buy::QuickBuyModalActions;
+use crate::app::state::{AppState, InputMode};
+
+impl AppState {
+    pub fn dry_run_request(&self) -> Option<DryRunRequest> {
+        if !self.config.dry_run() {
+            return None;
+        }
+
+        match self.ui.input_mode() {
+            InputMode::BuyConfirmation => {
+                let purchase = &self.modals.purchase;
+                if purchase.pending || purchase.success || purchase.error.is_some() {
+                    return None;
+                }
+
+                self.dry_run_purchase(&[BuyItem {
+                    product_id: purchase.selected_product_id.clone()?,
+                    quantity: purchase.quantity,
+                }])
+            }
+            InputMode::CartModal => {
+                let cart = &self.modals.cart;
+                if cart.pending || cart.success || cart.error.is_some() {
+                    return None;
+                }
+
+                self.dry_run_purchase(&self.cart_items())
+            }
+            InputMode::QuickBuyModal => {
+                let quick_buy = &self.modals.quick_buy;
+                if !quick_buy.confirming
+                    || quick_buy.pending
+                    || quick_buy.success
+                    || quick_buy.error.is_some()
+                {
+                    return None;
+                }
+
+                self.dry_run_purchase(&self.quick_buy_items())
+            }
+            InputMode::ParkingModal => {
+                let parking = &self.modals.parking;
+                if !parking.confirming
+                    || parking.pending
+                    || parking.success
+                    || parking.error.is_some()
+                {
+                    return None;
+                }
+
+                let request = self
+                    .parking_profile()
+                    .permit_request(self.selected_vehicle()?, self.selected_phone_number()?);
+
+                Some(DryRunRequest::parking(&request))
+            }
+            _ => None,
+        }
+    }
+
+    fn dry_run_purchase(&self, items: &[BuyItem]) -> Option<DryRunRequest> {
+        if items.is_empty() {
+            return None;
+        }
+
+        let member_id = self.user.member_id?;
+        let member_info = self.user.member_info.as_ref()?;
+
+        Some(DryRunRequest::purchase(
+            &self.config.api_url(),
+            member_id,
+            &format_buystring(&member_info.username, items),
+            self.config.room_id(),
+        ))
+    }
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::Arc;
+
+use tokio::sync::mpsc;
+use tokio::task::AbortHandle;
+
+use crate::api::backend::StregApi;
+use crate::app::schedule::{register_scheduled_parking, run_parking_hook, ScheduledParking};
+use crate::app::tasks::{PurchaseRequest, PurchaseTarget, TaskKind, TaskResult, UserData};
+use crate::models::ledger::LedgerEntry;
+use crate::models::parking::{ParkingPermitRequest, ParkingRegistration};
+use crate::ui::events::UIEvent;
+
+pub enum Effect {
+    LoadProducts,
+    LoadUser {
+        username: String,
+        login: bool,
+    },
+    Purchase {
+        target: PurchaseTarget,
+        request: PurchaseRequest,
+    },
+    RegisterParking(ParkingPermitRequest),
+    LogParking(ParkingRegistration),
+    LogSales(Vec<LedgerEntry>),
+    ScheduledParking(ScheduledParking),
+    Cancel(TaskKind),
+    CancelAll,
+    SaveConfig,
+    SaveCache,
+}
+
+impl Effect {
+    pub fn task_kind(&self) -> Option<TaskKind> {
+        match self {
+            Effect::LoadProducts => Some(TaskKind::Products),
+            Effect::LoadUser { .. } => Some(TaskKind::User),
+            Effect::Purchase { .. } => Some(TaskKind::Purchase),
+            Effect::RegisterParking(_) => Some(TaskKind::Parking),
+            Effect::ScheduledParking(_) => Some(TaskKind::ScheduledParking),
+            Effect::Cancel(_)
+            | Effect::CancelAll
+            | Effect::SaveConfig
+            | Effect::SaveCache
+            | Effect::LogParking(_)
+            | Effect::LogSales(_) => None,
+        }
+    }
+}
+
+pub struct EffectRunner {
+    api_client: Arc<dyn StregApi>,
+    tx: mpsc::Sender<UIEvent>,
+    running: HashMap<TaskKind, (u64, AbortHandle)>,
+    next_id: u64,
+}
+
+impl EffectRunner {
+    pub fn new(api_client: Arc<dyn StregApi>, tx: mpsc::Sender<UIEvent>) -> Self {
+        Self {
+            api_client,
+            tx,
+            running: HashMap::new(),
+            next_id: 0,
+        }
+    }
+
+    pub fn run(&mut self, effect: Effect) {
+        let api_client = self.api_client.clone();
+
+        match effect {
+            Effect::LoadProducts => self.spawn(TaskKind::Products, async move {
+                let (products, named_products) = tokio::join!(
+                    api_client.fetch_products(),
+                    api_client.fetch_named_products()
+                );
+
+                TaskResult::ProductsLoaded {
+                    products,
+                    named_products,
+                }
+            }),
+            Effect::LoadUser { username, login } => self.spawn(TaskKind::User, async move {
+                let result = match api_client.fetch_member_id(&username).await {
+                    Ok(Some(member_id)) => {
+                        let (member_info, latest_sales) = tokio::join!(
+                            api_client.fetch_member_info(member_id),
+                            api_client.fetch_latest_sales(member_id)
+                        );
+
+                        Ok(Some(UserData {
+                            member_id,
+                            member_info,
+                            latest_sales,
+                        }))
+                    }
+                    Ok(None) => Ok(None),
+                    Err(e) => Err(e),
+                };
+
+                TaskResult::UserLoaded {
+                    username,
+                    login,
+                    result,
+                }
+            }),
+            Effect::Purchase { target, request } => self.spawn(TaskKind::Purchase, async move {
+                let result = api_client
+                    .make_purchase(request.member_id, &request.buystring)
+                    .await;
+
+                TaskResult::PurchaseCompleted {
+                    target,
+                    items: request.items,
+                    result,
+                }
+            }),
+            Effect::RegisterParking(request) => self.spawn(TaskKind::Parking, async move {
+                let result = api_client.register_parking(&request).await.map(Box::new);
+
+                TaskResult::ParkingRegistered { request, result }
+            }),
+            Effect::ScheduledParking(scheduled) => {
+                self.spawn(TaskKind::ScheduledParking, async move {
+                    let result = register_scheduled_parking(api_client.as_ref(), &scheduled).await;
+                    let hook = run_parking_hook(&scheduled, &result).await;
+
+                    TaskResult::ScheduledParking {
+                        result: result.map(Box::new),
+                        hook,
+                    }
+                })
+            }
+            Effect::Cancel(kind) => self.cancel(kind),
+            Effect::CancelAll => {
+                for (_, (_, handle)) in self.running.drain() {
+                    handle.abort();
+                }
+            }
+            Effect::SaveConfig
+            | Effect::SaveCache
+            | Effect::LogParking(_)
+            | Effect::LogSales(_) => {}
+        }
+    }
+
+    pub fn finish(&mut self, id: u64, kind: TaskKind) -> bool {
+        match self.running.get(&kind) {
+            Some((running_id, _)) if *running_id == id => {
+                self.running.remove(&kind);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    fn cancel(&mut self, kind: TaskKind) {
+        if let Some((_, handle)) = self.running.remove(&kind) {
+            handle.abort();
+        }
+    }
+
+    fn spawn<F>(&mut self, kind: TaskKind, task: F)
+    where
+        F: Future<Output = TaskResult> + Send + 'static,
+    {
+        self.cancel(kind);
+
+        let id = self.next_id;
+        self.next_id += 1;
+
+        let tx = self.tx.clone();
+        let handle = tokio::spawn(async move {
+            let result = task.await;
+            let _ = tx.send(UIEvent::Task { id, result }).await;
+        });
+
+        self.running.insert(kind, (id, handle.abort_handle()));
+    }
+}
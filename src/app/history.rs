@@ -0,0 +1,135 @@
+use chrono::{DateTime, Datelike, Local, NaiveDate};
+
+use crate::models::sale::Sale;
+use crate::utils::formatters::sanitize_html;
+use crate::utils::money::Money;
+
+#[derive(Debug, Clone, Default)]
+pub struct HistoryFilter {
+    pub product: String,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub invalid_date: bool,
+}
+
+impl HistoryFilter {
+    fn matches(&self, sale: &Sale, time: DateTime<Local>) -> bool {
+        if self.invalid_date {
+            return false;
+        }
+
+        let date = time.date_naive();
+        if self.from.is_some_and(|from| date < from) || self.to.is_some_and(|to| date > to) {
+            return false;
+        }
+
+        let query = self.product.trim().to_lowercase();
+        query.is_empty() || sanitize_html(&sale.product).to_lowercase().contains(&query)
+    }
+}
+
+pub struct HistoryDay<'a> {
+    pub date: NaiveDate,
+    pub sales: Vec<(DateTime<Local>, &'a Sale)>,
+    pub total: Money,
+}
+
+impl HistoryDay<'_> {
+    pub fn row_count(&self) -> usize {
+        self.sales.len() + 1
+    }
+}
+
+pub fn group_sales_by_day<'a>(sales: &'a [Sale], filter: &HistoryFilter) -> Vec<HistoryDay<'a>> {
+    let mut matching: Vec<(DateTime<Local>, &Sale)> = sales
+        .iter()
+        .filter_map(|sale| Some((sale.local_time()?, sale)))
+        .filter(|(time, sale)| filter.matches(sale, *time))
+        .collect();
+    matching.sort_by_key(|(time, _)| std::cmp::Reverse(*time));
+
+    let mut days: Vec<HistoryDay> = Vec::new();
+
+    for (time, sale) in matching {
+        let date = time.date_naive();
+
+        match days.last_mut() {
+            Some(day) if day.date == date => {
+                day.sales.push((time, sale));
+                day.total = day.total + sale.price;
+            }
+            _ => days.push(HistoryDay {
+                date,
+                sales: vec![(time, sale)],
+                total: sale.price,
+            }),
+        }
+    }
+
+    days
+}
+
+pub fn parse_history_date(input: &str, today: NaiveDate) -> Option<NaiveDate> {
+    let input = input.trim();
+
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .or_else(|_| NaiveDate::parse_from_str(input, "%d/%m/%Y"))
+        .ok()
+        .or_else(|| {
+            let (day, month) = input.split_once('/')?;
+            NaiveDate::from_ymd_opt(today.year(), month.parse().ok()?, day.parse().ok()?)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sales() -> Vec<Sale> {
+        ["2026-10-16T12:00:00+02:00", "2026-10-17T09:00:00+02:00"]
+            .iter()
+            .map(|timestamp| Sale {
+                timestamp: timestamp.to_string(),
+                product: "Cola".to_string(),
+                price: Money::new(1000),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_history_dates() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 17).unwrap();
+
+        for input in ["2026-10-17", "17/10/2026", "17/10", " 17/10 "] {
+            assert_eq!(parse_history_date(input, today), Some(today), "{input}");
+        }
+        for input in ["2026-13-01", "32/10", "yesterday", ""] {
+            assert_eq!(parse_history_date(input, today), None, "{input}");
+        }
+    }
+
+    #[test]
+    fn date_range_is_inclusive() {
+        let day = NaiveDate::from_ymd_opt(2026, 10, 17);
+        let filter = HistoryFilter {
+            from: day,
+            to: day,
+            ..HistoryFilter::default()
+        };
+
+        let sales = sales();
+        let days = group_sales_by_day(&sales, &filter);
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[0].date, day.unwrap());
+    }
+
+    #[test]
+    fn invalid_date_matches_nothing() {
+        let filter = HistoryFilter {
+            invalid_date: true,
+            ..HistoryFilter::default()
+        };
+
+        assert!(group_sales_by_day(&sales(), &filter).is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use crate::app::effects::Effect;
+use crate::app::state::AppState;
+use crate::models::ledger::{merge_ledger_entry, LedgerEntry, LedgerScope};
+use crate::models::sale::Sale;
+
+impl AppState {
+    pub fn record_fetched_sales(&mut self) -> Option<Effect> {
+        let scope = self.ledger_scope()?;
+        let entries = self
+            .user
+            .latest_sales
+            .iter()
+            .rev()
+            .map(|sale| LedgerEntry::fetched(&scope, sale.clone()))
+            .collect();
+
+        self.record_ledger_entries(entries)
+    }
+
+    pub fn record_local_sales(&mut self, sales: Vec<Sale>) -> Option<Effect> {
+        let scope = self.ledger_scope()?;
+        let entries = sales
+            .into_iter()
+            .map(|sale| LedgerEntry::local(&scope, sale))
+            .collect();
+
+        self.record_ledger_entries(entries)
+    }
+
+    pub fn ledger_sales(&self) -> Vec<Sale> {
+        let Some(scope) = self.ledger_scope() else {
+            return Vec::new();
+        };
+
+        self.ledger
+            .iter()
+            .filter(|entry| entry.scope == scope)
+            .map(|entry| entry.sale.clone())
+            .collect()
+    }
+
+    fn ledger_scope(&self) -> Option<LedgerScope> {
+        Some(LedgerScope {
+            api_url: self.config.api_url(),
+            username: self.config.username()?.clone(),
+        })
+    }
+
+    fn record_ledger_entries(&mut self, entries: Vec<LedgerEntry>) -> Option<Effect> {
+        let added: Vec<LedgerEntry> = entries
+            .into_iter()
+            .filter(|entry| merge_ledger_entry(&mut self.ledger, entry.clone()))
+            .collect();
+
+        (!added.is_empty()).then_some(Effect::LogSales(added))
+    }
+}
@@ -1,3 +1,16 @@
-pub mod actions;
+pub mod action;
+pub mod buystring;
+pub mod cache;
+pub mod dry_run;
+pub mod effects;
+pub mod history;
+pub mod ledger;
 pub mod modals;
+pub mod plates;
+pub mod reducer;
+pub mod refresh;
+pub mod schedule;
+pub mod search;
 pub mod state;
+pub mod stats;
+pub mod tasks;
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use crate::app::buystring::{resolve_buy_items, resolved_total, BuyItem, MAX_QUANTITY};
+use crate::app::modals::error::ErrorModalActions;
+use crate::app::modals::search::SearchModalActions;
+use crate::app::state::{AppState, CartLine, InputMode};
+use crate::app::tasks::PurchaseRequest;
+use crate::error::AppError;
+use crate::utils::formatters::format_error_message;
+use crate::utils::money::Money;
+
+pub trait CartModalActions {
+    fn show_cart_modal(&mut self);
+
+    fn hide_cart_modal(&mut self);
+
+    fn add_to_cart(&mut self, product_id: &str);
+
+    fn add_selected_product_to_cart(&mut self);
+
+    fn add_search_result_to_cart(&mut self);
+
+    fn next_cart_line(&mut self);
+
+    fn previous_cart_line(&mut self);
+
+    fn increase_cart_quantity(&mut self);
+
+    fn decrease_cart_quantity(&mut self);
+
+    fn remove_cart_line(&mut self);
+
+    fn cart_total(&self) -> Money;
+
+    fn cart_items(&self) -> Vec<BuyItem>;
+
+    fn begin_cart_purchase(&mut self) -> Option<PurchaseRequest>;
+}
+
+impl CartModalActions for AppState {
+    fn show_cart_modal(&mut self) {
+        if let Err(message) = self.validate_user_for_purchase() {
+            let formatted_msg = format_error_message(&message, 50, 10);
+
+            self.show_error_modal(&formatted_msg, Some("Invalid User"));
+            return;
+        }
+
+        self.modals.cart.visible = true;
+        self.modals.cart.selected_index = 0;
+        self.modals.cart.error = None;
+        self.modals.cart.success = false;
+        self.push_input_mode(InputMode::CartModal);
+    }
+
+    fn hide_cart_modal(&mut self) {
+        self.modals.cart.visible = false;
+        self.modals.cart.error = None;
+        self.modals.cart.success = false;
+        self.pop_input_mode(InputMode::CartModal);
+    }
+
+    fn add_to_cart(&mut self, product_id: &str) {
+        if let Some(line) = self
+            .cart
+            .lines
+            .iter_mut()
+            .find(|line| line.product_id == product_id)
+        {
+            if line.quantity < MAX_QUANTITY {
+                line.quantity += 1;
+            }
+        } else {
+            self.cart.lines.push(CartLine {
+                product_id: product_id.to_string(),
+                quantity: 1,
+            });
+        }
+    }
+
+    fn add_selected_product_to_cart(&mut self) {
+        if let Some(i) = self.products.list_state.selected() {
+            let product_id = self.get_sorted_products().get(i).map(|p| p.id.clone());
+
+            if let Some(product_id) = product_id {
+                self.add_to_cart(&product_id);
+            }
+        }
+    }
+
+    fn add_search_result_to_cart(&mut self) {
+        if let Some(product_id) = self.get_selected_search_result().map(|p| p.id.clone()) {
+            self.add_to_cart(&product_id);
+        }
+    }
+
+    fn next_cart_line(&mut self) {
+        if !self.cart.lines.is_empty() {
+            self.modals.cart.selected_index =
+                (self.modals.cart.selected_index + 1) % self.cart.lines.len();
+        }
+    }
+
+    fn previous_cart_line(&mut self) {
+        if !self.cart.lines.is_empty() {
+            self.modals.cart.selected_index = if self.modals.cart.selected_index == 0 {
+                self.cart.lines.len() - 1
+            } else {
+                self.modals.cart.selected_index - 1
+            };
+        }
+    }
+
+    fn increase_cart_quantity(&mut self) {
+        if let Some(line) = self.cart.lines.get_mut(self.modals.cart.selected_index) {
+            if line.quantity < MAX_QUANTITY {
+                line.quantity += 1;
+            }
+        }
+    }
+
+    fn decrease_cart_quantity(&mut self) {
+        if let Some(line) = self.cart.lines.get_mut(self.modals.cart.selected_index) {
+            if line.quantity > 1 {
+                line.quantity -= 1;
+            }
+        }
+    }
+
+    fn remove_cart_line(&mut self) {
+        if self.modals.cart.selected_index < self.cart.lines.len() {
+            self.cart.lines.remove(self.modals.cart.selected_index);
+
+            if self.modals.cart.selected_index >= self.cart.lines.len() {
+                self.modals.cart.selected_index = self.cart.lines.len().saturating_sub(1);
+            }
+        }
+    }
+
+    fn cart_total(&self) -> Money {
+        self.cart
+            .lines
+            .iter()
+            .filter_map(|line| {
+                self.products
+                    .items
+                    .get(&line.product_id)
+                    .map(|product| product.price * line.quantity)
+            })
+            .fold(Money::new(0), |total, line_total| total + line_total)
+    }
+
+    fn cart_items(&self) -> Vec<BuyItem> {
+        self.cart
+            .lines
+            .iter()
+            .map(|line| BuyItem {
+                product_id: line.product_id.clone(),
+                quantity: line.quantity,
+            })
+            .collect()
+    }
+
+    fn begin_cart_purchase(&mut self) -> Option<PurchaseRequest> {
+        if let Err(message) = self.validate_user_for_purchase() {
+            let formatted_msg = format_error_message(&message, 50, 10);
+
+            self.show_error_modal(&formatted_msg, Some("Invalid User"));
+            return None;
+        }
+
+        if self.cart.lines.is_empty() {
+            self.modals.cart.error = Some("Your cart is empty".to_string());
+            return None;
+        }
+
+        let items = self.cart_items();
+        let total = match resolve_buy_items(&items, &self.products.items, &HashMap::new())
+            .and_then(|lines| resolved_total(&lines))
+        {
+            Ok(total) => total,
+            Err(AppError::Input(message)) => {
+                self.modals.cart.error = Some(message);
+                return None;
+            }
+            Err(e) => {
+                self.modals.cart.error = Some(e.to_string());
+                return None;
+            }
+        };
+
+        match self.purchase_request(items, total) {
+            Ok(request) => {
+                self.modals.cart.error = None;
+                self.modals.cart.success = false;
+                self.modals.cart.pending = true;
+                Some(request)
+            }
+            Err(message) => {
+                self.modals.cart.error = Some(message);
+                None
+            }
+        }
+    }
+}
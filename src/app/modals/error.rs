@@ -3,6 +3,8 @@ use crate::app::state::{AppState, InputMode};
 pub trait ErrorModalActions {
     fn show_error_modal(&mut self, message: &str, title: Option<&str>);
 
+    fn show_success_modal(&mut self, message: &str, title: Option<&str>);
+
     fn hide_error_modal(&mut self);
 }
 
@@ -11,15 +13,22 @@ impl ErrorModalActions for AppState {
         self.modals.error.visible = true;
         self.modals.error.message = Some(message.to_string());
         self.modals.error.title = title.map(|t| t.to_string());
+        self.modals.error.success = false;
 
         self.push_input_mode(InputMode::ErrorModal);
     }
 
+    fn show_success_modal(&mut self, message: &str, title: Option<&str>) {
+        self.show_error_modal(message, title);
+        self.modals.error.success = true;
+    }
+
     fn hide_error_modal(&mut self) {
         self.modals.error.visible = false;
         self.modals.error.message = None;
         self.modals.error.title = None;
+        self.modals.error.success = false;
 
-        self.pop_input_mode();
+        self.pop_input_mode(InputMode::ErrorModal);
     }
 }
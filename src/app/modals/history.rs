@@ -0,0 +1,139 @@
+use chrono::Local;
+
+use crate::app::history::{group_sales_by_day, parse_history_date, HistoryFilter};
+use crate::app::state::{AppState, HistoryField, InputMode};
+
+pub const HISTORY_CHROME_HEIGHT: u16 = 13;
+
+pub trait HistoryModalActions {
+    fn show_history_modal(&mut self);
+
+    fn hide_history_modal(&mut self);
+
+    fn history_filter(&self) -> HistoryFilter;
+
+    fn scroll_history_down(&mut self, rows: usize);
+
+    fn scroll_history_up(&mut self, rows: usize);
+
+    fn history_page_size(&self) -> usize;
+
+    fn edit_history_field(&mut self, field: HistoryField);
+
+    fn next_history_field(&mut self);
+
+    fn history_input(&mut self, c: char);
+
+    fn history_backspace(&mut self);
+
+    fn finish_history_edit(&mut self);
+
+    fn clear_history_filters(&mut self);
+}
+
+impl HistoryModalActions for AppState {
+    fn show_history_modal(&mut self) {
+        self.modals.history.visible = true;
+        self.modals.history.scroll = 0;
+        self.modals.history.editing = None;
+
+        self.push_input_mode(InputMode::HistoryModal);
+    }
+
+    fn hide_history_modal(&mut self) {
+        self.modals.history.visible = false;
+        self.modals.history.editing = None;
+
+        self.pop_input_mode(InputMode::HistoryModal);
+    }
+
+    fn history_filter(&self) -> HistoryFilter {
+        let today = Local::now().date_naive();
+        let history = &self.modals.history;
+
+        let from = parse_history_date(&history.from, today);
+        let to = parse_history_date(&history.to, today);
+
+        HistoryFilter {
+            product: history.product.clone(),
+            invalid_date: (from.is_none() && !history.from.trim().is_empty())
+                || (to.is_none() && !history.to.trim().is_empty()),
+            from,
+            to,
+        }
+    }
+
+    fn scroll_history_down(&mut self, rows: usize) {
+        let total_rows: usize = group_sales_by_day(&self.ledger_sales(), &self.history_filter())
+            .iter()
+            .map(|day| day.row_count())
+            .sum();
+        let max_scroll = total_rows.saturating_sub(self.history_page_size());
+
+        let scroll = &mut self.modals.history.scroll;
+        *scroll = scroll.saturating_add(rows).min(max_scroll);
+    }
+
+    fn scroll_history_up(&mut self, rows: usize) {
+        let scroll = &mut self.modals.history.scroll;
+        *scroll = scroll.saturating_sub(rows);
+    }
+
+    fn history_page_size(&self) -> usize {
+        self.ui
+            .terminal_height
+            .saturating_sub(HISTORY_CHROME_HEIGHT)
+            .max(1) as usize
+    }
+
+    fn edit_history_field(&mut self, field: HistoryField) {
+        self.modals.history.editing = Some(field);
+    }
+
+    fn next_history_field(&mut self) {
+        self.modals.history.editing = match self.modals.history.editing {
+            Some(HistoryField::Product) => Some(HistoryField::From),
+            Some(HistoryField::From) => Some(HistoryField::To),
+            Some(HistoryField::To) | None => Some(HistoryField::Product),
+        };
+    }
+
+    fn history_input(&mut self, c: char) {
+        let history = &mut self.modals.history;
+
+        match history.editing {
+            Some(HistoryField::Product) => history.product.push(c),
+            Some(HistoryField::From) => history.from.push(c),
+            Some(HistoryField::To) => history.to.push(c),
+            None => return,
+        }
+
+        history.scroll = 0;
+    }
+
+    fn history_backspace(&mut self) {
+        let history = &mut self.modals.history;
+
+        match history.editing {
+            Some(HistoryField::Product) => history.product.pop(),
+            Some(HistoryField::From) => history.from.pop(),
+            Some(HistoryField::To) => history.to.pop(),
+            None => return,
+        };
+
+        history.scroll = 0;
+    }
+
+    fn finish_history_edit(&mut self) {
+        self.modals.history.editing = None;
+    }
+
+    fn clear_history_filters(&mut self) {
+        let history = &mut self.modals.history;
+
+        history.product.clear();
+        history.from.clear();
+        history.to.clear();
+        history.scroll = 0;
+    }
+}
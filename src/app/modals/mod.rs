@@ -1,6 +1,11 @@
+pub mod cart;
 pub mod error;
+pub mod history;
 pub mod parking;
+pub mod parking_log;
 pub mod purchase;
+pub mod quick_buy;
 pub mod search;
+pub mod stats;
 pub mod terminal_size;
 pub mod username;
@@ -1,6 +1,11 @@
-use crate::app::state::{AppState, InputMode};
-use crate::config::store::save_config;
+use crate::app::plates::{normalize_plate, validate_vehicle};
+use crate::app::state::{AppState, InputMode, ParkingEditor};
 use crate::error::{AppError, Result};
+use crate::models::parking::{ParkingPermitRequest, ParkingProfile, Vehicle};
+
+const MAX_PHONE_DIGITS: usize = 15;
+const MAX_PLATE_LENGTH: usize = 12;
+const MAX_NICKNAME_LENGTH: usize = 20;
 
 pub trait ParkingModalActions {
     fn show_parking_modal(&mut self);
@@ -11,7 +16,39 @@ pub trait ParkingModalActions {
 
     fn prev_parking_field(&mut self);
 
+    fn next_parking_profile(&mut self);
+
+    fn prev_parking_profile(&mut self);
+
+    fn parking_profile(&self) -> ParkingProfile;
+
+    fn next_parking_entry(&mut self);
+
+    fn previous_parking_entry(&mut self);
+
+    fn selected_phone_number(&self) -> Option<&String>;
+
+    fn selected_vehicle(&self) -> Option<&Vehicle>;
+
+    fn add_parking_entry(&mut self);
+
+    fn edit_parking_entry(&mut self);
+
+    fn delete_parking_entry(&mut self) -> bool;
+
+    fn parking_editor_input(&mut self, c: char);
+
+    fn parking_editor_backspace(&mut self);
+
+    fn next_parking_editor_field(&mut self);
+
+    fn save_parking_entry(&mut self) -> Result<()>;
+
+    fn cancel_parking_edit(&mut self);
+
     fn confirm_parking(&mut self) -> Result<()>;
+
+    fn begin_parking_registration(&mut self) -> Option<ParkingPermitRequest>;
 }
 
 impl ParkingModalActions for AppState {
@@ -19,29 +56,38 @@ impl ParkingModalActions for AppState {
         self.modals.parking.visible = true;
         self.push_input_mode(InputMode::ParkingModal);
 
-        self.modals.parking.phone_input = self.config.phone_number.clone().unwrap_or_default();
-        self.modals.parking.license_plate_input =
-            self.config.license_plate.clone().unwrap_or_default();
+        self.modals.parking.phone_index = self
+            .config
+            .phone_number
+            .as_ref()
+            .and_then(|number| self.config.phone_numbers.iter().position(|n| n == number))
+            .unwrap_or(0);
+        self.modals.parking.vehicle_index = self
+            .config
+            .license_plate
+            .as_ref()
+            .and_then(|plate| self.config.vehicles.iter().position(|v| &v.plate == plate))
+            .unwrap_or(0);
         self.modals.parking.current_field = 0;
+        self.modals.parking.editor = None;
+        self.modals.parking.profile_index = self.config.selected_parking_profile();
         self.modals.parking.confirming = false;
         self.modals.parking.error = None;
         self.modals.parking.success = false;
+        self.modals.parking.permit = None;
     }
 
     fn hide_parking_modal(&mut self) {
         self.modals.parking.visible = false;
+        self.pop_input_mode(InputMode::ParkingModal);
 
-        if self.modals.parking.confirming {
-            self.pop_input_mode();
-        }
-        self.pop_input_mode();
-
-        self.modals.parking.phone_input.clear();
-        self.modals.parking.license_plate_input.clear();
         self.modals.parking.current_field = 0;
+        self.modals.parking.editor = None;
         self.modals.parking.confirming = false;
         self.modals.parking.error = None;
         self.modals.parking.success = false;
+        self.modals.parking.permit = None;
+        self.modals.parking.pending = false;
     }
 
     fn next_parking_field(&mut self) {
@@ -56,57 +102,317 @@ impl ParkingModalActions for AppState {
         };
     }
 
-    fn confirm_parking(&mut self) -> Result<()> {
-        let phone = self.modals.parking.phone_input.trim();
-        let license_plate = self
-            .modals
-            .parking
-            .license_plate_input
-            .trim()
-            .to_uppercase();
-
-        if phone.is_empty() {
-            return Err(AppError::Input("Phone number cannot be empty".to_string()));
+    fn next_parking_profile(&mut self) {
+        let count = self.config.parking_profiles().len();
+        self.modals.parking.profile_index = (self.modals.parking.profile_index + 1) % count;
+    }
+
+    fn prev_parking_profile(&mut self) {
+        let count = self.config.parking_profiles().len();
+        self.modals.parking.profile_index = (self.modals.parking.profile_index + count - 1) % count;
+    }
+
+    fn parking_profile(&self) -> ParkingProfile {
+        self.config
+            .parking_profiles()
+            .get(self.modals.parking.profile_index)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn next_parking_entry(&mut self) {
+        let (index, len) = selected_list(self);
+
+        if len > 0 && *index < len - 1 {
+            *index += 1;
         }
+    }
+
+    fn previous_parking_entry(&mut self) {
+        let (index, _) = selected_list(self);
+
+        *index = index.saturating_sub(1);
+    }
+
+    fn selected_phone_number(&self) -> Option<&String> {
+        self.config
+            .phone_numbers
+            .get(self.modals.parking.phone_index)
+    }
 
-        if license_plate.is_empty() {
-            return Err(AppError::Input("License plate cannot be empty".to_string()));
+    fn selected_vehicle(&self) -> Option<&Vehicle> {
+        self.config.vehicles.get(self.modals.parking.vehicle_index)
+    }
+
+    fn add_parking_entry(&mut self) {
+        self.modals.parking.editor = Some(if self.modals.parking.current_field == 0 {
+            ParkingEditor::Phone {
+                index: None,
+                number: String::new(),
+            }
+        } else {
+            ParkingEditor::Vehicle {
+                index: None,
+                field: 0,
+                plate: String::new(),
+                country: self.parking_profile().country,
+                nickname: String::new(),
+            }
+        });
+    }
+
+    fn edit_parking_entry(&mut self) {
+        let editor = if self.modals.parking.current_field == 0 {
+            self.selected_phone_number()
+                .map(|number| ParkingEditor::Phone {
+                    index: Some(self.modals.parking.phone_index),
+                    number: number.clone(),
+                })
+        } else {
+            self.selected_vehicle()
+                .map(|vehicle| ParkingEditor::Vehicle {
+                    index: Some(self.modals.parking.vehicle_index),
+                    field: 0,
+                    plate: vehicle.plate.clone(),
+                    country: vehicle.country.clone(),
+                    nickname: vehicle.nickname.clone().unwrap_or_default(),
+                })
+        };
+
+        if editor.is_some() {
+            self.modals.parking.editor = editor;
         }
+    }
 
-        if !phone.chars().all(|c| c.is_ascii_digit()) || phone.len() != 8 {
-            return Err(AppError::Input("Phone number must be 8 digits".to_string()));
+    fn delete_parking_entry(&mut self) -> bool {
+        let parking = &mut self.modals.parking;
+
+        let (index, len) = if parking.current_field == 0 {
+            if parking.phone_index >= self.config.phone_numbers.len() {
+                return false;
+            }
+            let removed = self.config.phone_numbers.remove(parking.phone_index);
+            if self.config.phone_number.as_ref() == Some(&removed) {
+                self.config.phone_number = None;
+            }
+            (&mut parking.phone_index, self.config.phone_numbers.len())
+        } else {
+            if parking.vehicle_index >= self.config.vehicles.len() {
+                return false;
+            }
+            let removed = self.config.vehicles.remove(parking.vehicle_index);
+            if self.config.license_plate.as_ref() == Some(&removed.plate) {
+                self.config.license_plate = None;
+            }
+            (&mut parking.vehicle_index, self.config.vehicles.len())
+        };
+
+        *index = (*index).min(len.saturating_sub(1));
+        true
+    }
+
+    fn parking_editor_input(&mut self, c: char) {
+        match self.modals.parking.editor {
+            Some(ParkingEditor::Phone { ref mut number, .. })
+                if c.is_ascii_digit() && number.len() < MAX_PHONE_DIGITS =>
+            {
+                number.push(c);
+            }
+            Some(ParkingEditor::Vehicle {
+                field,
+                ref mut plate,
+                ref mut country,
+                ref mut nickname,
+                ..
+            }) => match field {
+                0 if (c.is_alphanumeric() || c == ' ' || c == '-')
+                    && plate.chars().count() < MAX_PLATE_LENGTH =>
+                {
+                    plate.extend(c.to_uppercase());
+                }
+                1 if c.is_ascii_alphabetic() && country.len() < 2 => {
+                    country.push(c.to_ascii_uppercase());
+                }
+                2 if !c.is_control() && nickname.chars().count() < MAX_NICKNAME_LENGTH => {
+                    nickname.push(c);
+                }
+                _ => {}
+            },
+            _ => {}
         }
+    }
 
-        if license_plate.len() != 7 {
-            return Err(AppError::Input(
-                "License plate must be exactly 7 characters".to_string(),
-            ));
+    fn parking_editor_backspace(&mut self) {
+        match self.modals.parking.editor {
+            Some(ParkingEditor::Phone { ref mut number, .. }) => {
+                number.pop();
+            }
+            Some(ParkingEditor::Vehicle {
+                field,
+                ref mut plate,
+                ref mut country,
+                ref mut nickname,
+                ..
+            }) => {
+                match field {
+                    0 => plate.pop(),
+                    1 => country.pop(),
+                    _ => nickname.pop(),
+                };
+            }
+            None => {}
+        }
+    }
+
+    fn next_parking_editor_field(&mut self) {
+        if let Some(ParkingEditor::Vehicle { ref mut field, .. }) = self.modals.parking.editor {
+            *field = (*field + 1) % 3;
         }
+    }
+
+    fn save_parking_entry(&mut self) -> Result<()> {
+        let Some(editor) = self.modals.parking.editor.clone() else {
+            return Ok(());
+        };
+
+        match editor {
+            ParkingEditor::Phone { index, number } => {
+                if number.is_empty() {
+                    return Err(AppError::Input("Phone number cannot be empty".to_string()));
+                }
+
+                if self.config.phone_numbers.contains(&number)
+                    && index.and_then(|i| self.config.phone_numbers.get(i)) != Some(&number)
+                {
+                    return Err(AppError::Input(format!("{number} is already saved")));
+                }
+
+                self.modals.parking.phone_index =
+                    upsert(&mut self.config.phone_numbers, index, number);
+            }
+            ParkingEditor::Vehicle {
+                index,
+                plate,
+                country,
+                nickname,
+                ..
+            } => {
+                let nickname = nickname.trim();
+                let vehicle = Vehicle {
+                    plate: normalize_plate(&plate),
+                    country,
+                    nickname: (!nickname.is_empty()).then(|| nickname.to_string()),
+                };
+
+                validate_vehicle(&vehicle)?;
+
+                let duplicate = self
+                    .config
+                    .vehicles
+                    .iter()
+                    .enumerate()
+                    .any(|(i, v)| v.plate == vehicle.plate && Some(i) != index);
+                if duplicate {
+                    return Err(AppError::Input(format!(
+                        "{} is already saved",
+                        vehicle.plate
+                    )));
+                }
+
+                self.modals.parking.vehicle_index =
+                    upsert(&mut self.config.vehicles, index, vehicle);
+            }
+        }
+
+        self.modals.parking.editor = None;
+
+        Ok(())
+    }
+
+    fn cancel_parking_edit(&mut self) {
+        self.modals.parking.editor = None;
+    }
 
-        let chars: Vec<char> = license_plate.chars().collect();
+    fn confirm_parking(&mut self) -> Result<()> {
+        let profile = self.parking_profile();
 
-        if !chars[0].is_ascii_alphabetic() || !chars[1].is_ascii_alphabetic() {
+        let Some(phone) = self.selected_phone_number().cloned() else {
             return Err(AppError::Input(
-                "License plate must start with 2 letters".to_string(),
+                "Add a phone number first, select the list with 'tab' and press 'a'".to_string(),
             ));
-        }
+        };
 
-        if !chars[2..].iter().all(|c| c.is_ascii_digit()) {
+        let Some(vehicle) = self.selected_vehicle().cloned() else {
             return Err(AppError::Input(
-                "License plate must end with 5 digits".to_string(),
+                "Add a vehicle first, select the list with 'tab' and press 'a'".to_string(),
             ));
+        };
+
+        let (min_digits, max_digits) = profile.phone_length();
+        if phone.len() < min_digits || phone.len() > max_digits {
+            let message = if min_digits == max_digits {
+                format!(
+                    "Phone number must be {min_digits} digits for +{}",
+                    profile.phone_prefix
+                )
+            } else {
+                format!(
+                    "Phone number must be {min_digits}-{max_digits} digits for +{}",
+                    profile.phone_prefix
+                )
+            };
+            return Err(AppError::Input(message));
         }
 
-        self.config.phone_number = Some(phone.to_string());
-        self.config.license_plate = Some(license_plate.clone());
-        save_config(&self.config)?;
+        validate_vehicle(&vehicle)?;
 
-        self.modals.parking.phone_input = phone.to_string();
-        self.modals.parking.license_plate_input = license_plate;
+        self.config.phone_number = Some(phone);
+        self.config.license_plate = Some(vehicle.plate);
+        if !self.config.parking_profiles.is_empty() {
+            self.config.parking_profile = Some(profile.name);
+        }
 
         self.modals.parking.confirming = true;
-        self.push_input_mode(InputMode::ParkingConfirmation);
 
         Ok(())
     }
+
+    fn begin_parking_registration(&mut self) -> Option<ParkingPermitRequest> {
+        if self.modals.parking.pending {
+            return None;
+        }
+
+        let request = self
+            .parking_profile()
+            .permit_request(self.selected_vehicle()?, self.selected_phone_number()?);
+
+        self.modals.parking.pending = true;
+        self.modals.parking.error = None;
+        self.modals.parking.success = false;
+
+        Some(request)
+    }
+}
+
+fn selected_list(state: &mut AppState) -> (&mut usize, usize) {
+    let parking = &mut state.modals.parking;
+
+    if parking.current_field == 0 {
+        (&mut parking.phone_index, state.config.phone_numbers.len())
+    } else {
+        (&mut parking.vehicle_index, state.config.vehicles.len())
+    }
+}
+
+fn upsert<T>(items: &mut Vec<T>, index: Option<usize>, item: T) -> usize {
+    match index {
+        Some(index) if index < items.len() => {
+            items[index] = item;
+            index
+        }
+        _ => {
+            items.push(item);
+            items.len() - 1
+        }
+    }
 }
@@ -0,0 +1,50 @@
+use chrono::{DateTime, Local};
+
+use crate::app::state::{AppState, InputMode};
+use crate::models::parking::ParkingRegistration;
+
+pub trait ParkingLogModalActions {
+    fn show_parking_log_modal(&mut self);
+
+    fn hide_parking_log_modal(&mut self);
+
+    fn next_parking_log_entry(&mut self);
+
+    fn previous_parking_log_entry(&mut self);
+
+    fn active_parking(&self, now: DateTime<Local>) -> Option<&ParkingRegistration>;
+}
+
+impl ParkingLogModalActions for AppState {
+    fn show_parking_log_modal(&mut self) {
+        self.modals.parking_log.visible = true;
+        self.modals.parking_log.selected_index = 0;
+
+        self.push_input_mode(InputMode::ParkingLogModal);
+    }
+
+    fn hide_parking_log_modal(&mut self) {
+        self.modals.parking_log.visible = false;
+
+        self.pop_input_mode(InputMode::ParkingLogModal);
+    }
+
+    fn next_parking_log_entry(&mut self) {
+        let index = &mut self.modals.parking_log.selected_index;
+        if *index + 1 < self.parking_log.len() {
+            *index += 1;
+        }
+    }
+
+    fn previous_parking_log_entry(&mut self) {
+        let index = &mut self.modals.parking_log.selected_index;
+        *index = index.saturating_sub(1);
+    }
+
+    fn active_parking(&self, now: DateTime<Local>) -> Option<&ParkingRegistration> {
+        self.parking_log
+            .iter()
+            .filter(|registration| registration.remaining(now).is_some())
+            .max_by_key(|registration| registration.expires_at())
+    }
+}
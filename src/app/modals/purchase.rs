@@ -1,25 +1,28 @@
+use crate::app::buystring::{BuyItem, MAX_QUANTITY};
 use crate::app::modals::error::ErrorModalActions;
 use crate::app::state::{AppState, InputMode};
-use crate::error::Result;
+use crate::app::tasks::PurchaseRequest;
+use crate::utils::formatters::format_error_message;
 
 pub trait PurchaseModalActions {
-    fn show_purchase_modal(&mut self) -> Result<()>;
+    fn show_purchase_modal(&mut self);
 
     fn hide_purchase_modal(&mut self);
 
     fn increase_quantity(&mut self);
 
     fn decrease_quantity(&mut self);
+
+    fn begin_purchase(&mut self) -> Option<PurchaseRequest>;
 }
 
 impl PurchaseModalActions for AppState {
-    fn show_purchase_modal(&mut self) -> Result<()> {
+    fn show_purchase_modal(&mut self) {
         if let Err(message) = self.validate_user_for_purchase() {
-            use crate::utils::formatters::format_error_message;
             let formatted_msg = format_error_message(&message, 50, 10);
 
             self.show_error_modal(&formatted_msg, Some("Invalid User"));
-            return Ok(());
+            return;
         }
 
         if let Some(i) = self.products.list_state.selected() {
@@ -32,20 +35,21 @@ impl PurchaseModalActions for AppState {
                 self.push_input_mode(InputMode::BuyConfirmation);
                 self.modals.purchase.error = None;
                 self.modals.purchase.success = false;
+                self.modals.purchase.response = None;
             }
         }
-        Ok(())
     }
 
     fn hide_purchase_modal(&mut self) {
         self.modals.purchase.visible = false;
-        self.pop_input_mode();
+        self.pop_input_mode(InputMode::BuyConfirmation);
         self.modals.purchase.selected_product_id = None;
         self.modals.purchase.quantity = 1;
+        self.modals.purchase.response = None;
     }
 
     fn increase_quantity(&mut self) {
-        if self.modals.purchase.quantity < 99 {
+        if self.modals.purchase.quantity < MAX_QUANTITY {
             self.modals.purchase.quantity += 1;
         }
     }
@@ -55,4 +59,35 @@ impl PurchaseModalActions for AppState {
             self.modals.purchase.quantity -= 1;
         }
     }
+
+    fn begin_purchase(&mut self) -> Option<PurchaseRequest> {
+        if let Err(message) = self.validate_user_for_purchase() {
+            let formatted_msg = format_error_message(&message, 50, 10);
+
+            self.show_error_modal(&formatted_msg, Some("Invalid User"));
+            return None;
+        }
+
+        let product_id = self.modals.purchase.selected_product_id.clone()?;
+        let quantity = self.modals.purchase.quantity;
+        let total_cost = self.products.items.get(&product_id)?.price * quantity;
+
+        let items = vec![BuyItem {
+            product_id,
+            quantity,
+        }];
+
+        match self.purchase_request(items, total_cost) {
+            Ok(request) => {
+                self.modals.purchase.error = None;
+                self.modals.purchase.success = false;
+                self.modals.purchase.pending = true;
+                Some(request)
+            }
+            Err(message) => {
+                self.modals.purchase.error = Some(message);
+                None
+            }
+        }
+    }
 }
@@ -0,0 +1,132 @@
+use crate::app::buystring::{
+    parse_buy_items, resolve_buy_items, resolved_items, resolved_total, BuyItem,
+};
+use crate::app::modals::error::ErrorModalActions;
+use crate::app::state::{AppState, InputMode};
+use crate::app::tasks::PurchaseRequest;
+use crate::error::AppError;
+use crate::utils::formatters::format_error_message;
+use crate::utils::money::Money;
+
+pub trait QuickBuyModalActions {
+    fn show_quick_buy_modal(&mut self);
+
+    fn hide_quick_buy_modal(&mut self);
+
+    fn update_quick_buy_preview(&mut self);
+
+    fn confirm_quick_buy(&mut self);
+
+    fn cancel_quick_buy_confirmation(&mut self);
+
+    fn quick_buy_total(&self) -> Money;
+
+    fn quick_buy_items(&self) -> Vec<BuyItem>;
+
+    fn begin_quick_buy(&mut self) -> Option<PurchaseRequest>;
+}
+
+impl QuickBuyModalActions for AppState {
+    fn show_quick_buy_modal(&mut self) {
+        if let Err(message) = self.validate_user_for_purchase() {
+            let formatted_msg = format_error_message(&message, 50, 10);
+
+            self.show_error_modal(&formatted_msg, Some("Invalid User"));
+            return;
+        }
+
+        self.modals.quick_buy.visible = true;
+        self.modals.quick_buy.input.clear();
+        self.modals.quick_buy.lines.clear();
+        self.modals.quick_buy.parse_error = None;
+        self.modals.quick_buy.confirming = false;
+        self.modals.quick_buy.error = None;
+        self.modals.quick_buy.success = false;
+        self.push_input_mode(InputMode::QuickBuyModal);
+    }
+
+    fn hide_quick_buy_modal(&mut self) {
+        self.modals.quick_buy.visible = false;
+        self.modals.quick_buy.input.clear();
+        self.modals.quick_buy.lines.clear();
+        self.modals.quick_buy.parse_error = None;
+        self.modals.quick_buy.confirming = false;
+        self.modals.quick_buy.error = None;
+        self.modals.quick_buy.success = false;
+        self.pop_input_mode(InputMode::QuickBuyModal);
+    }
+
+    fn update_quick_buy_preview(&mut self) {
+        self.modals.quick_buy.lines.clear();
+        self.modals.quick_buy.parse_error = None;
+
+        if self.modals.quick_buy.input.trim().is_empty() {
+            return;
+        }
+
+        let resolved = parse_buy_items(&self.modals.quick_buy.input).and_then(|items| {
+            let lines =
+                resolve_buy_items(&items, &self.products.items, &self.products.named_products)?;
+            resolved_total(&lines)?;
+            Ok(lines)
+        });
+
+        match resolved {
+            Ok(lines) => self.modals.quick_buy.lines = lines,
+            Err(AppError::Input(message)) => self.modals.quick_buy.parse_error = Some(message),
+            Err(e) => self.modals.quick_buy.parse_error = Some(e.to_string()),
+        }
+    }
+
+    fn confirm_quick_buy(&mut self) {
+        self.update_quick_buy_preview();
+
+        if self.modals.quick_buy.lines.is_empty() {
+            if self.modals.quick_buy.parse_error.is_none() {
+                self.modals.quick_buy.parse_error = Some("Nothing to buy".to_string());
+            }
+            return;
+        }
+
+        self.modals.quick_buy.confirming = true;
+    }
+
+    fn cancel_quick_buy_confirmation(&mut self) {
+        self.modals.quick_buy.confirming = false;
+    }
+
+    fn quick_buy_total(&self) -> Money {
+        resolved_total(&self.modals.quick_buy.lines).unwrap_or(Money::new(0))
+    }
+
+    fn quick_buy_items(&self) -> Vec<BuyItem> {
+        resolved_items(&self.modals.quick_buy.lines)
+    }
+
+    fn begin_quick_buy(&mut self) -> Option<PurchaseRequest> {
+        if let Err(message) = self.validate_user_for_purchase() {
+            let formatted_msg = format_error_message(&message, 50, 10);
+
+            self.show_error_modal(&formatted_msg, Some("Invalid User"));
+            return None;
+        }
+
+        if self.modals.quick_buy.lines.is_empty() {
+            self.modals.quick_buy.error = Some("Nothing to buy".to_string());
+            return None;
+        }
+
+        match self.purchase_request(self.quick_buy_items(), self.quick_buy_total()) {
+            Ok(request) => {
+                self.modals.quick_buy.error = None;
+                self.modals.quick_buy.success = false;
+                self.modals.quick_buy.pending = true;
+                Some(request)
+            }
+            Err(message) => {
+                self.modals.quick_buy.error = Some(message);
+                None
+            }
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::app::search::search_products;
 use crate::app::state::{AppState, InputMode};
 use crate::models::product::Product;
 
@@ -13,6 +14,8 @@ pub trait SearchModalActions {
     fn get_selected_search_result(&self) -> Option<&Product>;
 
     fn select_product_from_search(&mut self);
+
+    fn update_search_results(&mut self);
 }
 
 impl SearchModalActions for AppState {
@@ -26,7 +29,7 @@ impl SearchModalActions for AppState {
 
     fn hide_search_modal(&mut self) {
         self.modals.search.visible = false;
-        self.pop_input_mode();
+        self.pop_input_mode(InputMode::SearchMode);
         self.modals.search.input.clear();
         self.modals.search.results.clear();
     }
@@ -71,4 +74,16 @@ impl SearchModalActions for AppState {
             }
         }
     }
+
+    fn update_search_results(&mut self) {
+        self.modals.search.results = search_products(
+            &self.products.items,
+            &self.products.named_products,
+            &self.modals.search.input,
+        );
+
+        if !self.modals.search.results.is_empty() {
+            self.modals.search.selected_index = 0;
+        }
+    }
 }
@@ -0,0 +1,21 @@
+use crate::app::state::{AppState, InputMode};
+
+pub trait StatsModalActions {
+    fn show_stats_modal(&mut self);
+
+    fn hide_stats_modal(&mut self);
+}
+
+impl StatsModalActions for AppState {
+    fn show_stats_modal(&mut self) {
+        self.modals.stats.visible = true;
+
+        self.push_input_mode(InputMode::StatsModal);
+    }
+
+    fn hide_stats_modal(&mut self) {
+        self.modals.stats.visible = false;
+
+        self.pop_input_mode(InputMode::StatsModal);
+    }
+}
@@ -21,7 +21,7 @@ impl TerminalSizeModalActions for AppState {
     fn hide_terminal_size_modal(&mut self) {
         self.modals.terminal_size.visible = false;
 
-        self.pop_input_mode();
+        self.pop_input_mode(InputMode::TerminalSizeModal);
     }
 
     fn check_terminal_size(&mut self, width: u16, height: u16) {
@@ -1,5 +1,4 @@
 use crate::app::state::{AppState, InputMode};
-use crate::config::store::save_config;
 use crate::error::{AppError, Result};
 
 pub trait UsernameModalActions {
@@ -15,12 +14,12 @@ impl UsernameModalActions for AppState {
         self.modals.username.visible = true;
         self.push_input_mode(InputMode::EditingUsername);
 
-        self.modals.username.input = self.config.username.clone().unwrap_or_default();
+        self.modals.username.input = self.config.username().cloned().unwrap_or_default();
     }
 
     fn hide_username_modal(&mut self) {
         self.modals.username.visible = false;
-        self.pop_input_mode();
+        self.pop_input_mode(InputMode::EditingUsername);
         self.modals.username.input.clear();
     }
 
@@ -31,8 +30,7 @@ impl UsernameModalActions for AppState {
             return Err(AppError::Input("Username cannot be empty".to_string()));
         }
 
-        self.config.username = Some(new_username);
-        save_config(&self.config)?;
+        self.config.set_username(new_username);
         self.hide_username_modal();
 
         Ok(())
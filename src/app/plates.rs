@@ -0,0 +1,300 @@
+use crate::error::{AppError, Result};
+use crate::models::parking::Vehicle;
+
+pub trait PlateValidator: Sync {
+    fn country(&self) -> &'static str;
+
+    fn validate(&self, plate: &str) -> std::result::Result<(), String>;
+}
+
+struct DanishPlate;
+struct GermanPlate;
+struct SwedishPlate;
+struct NorwegianPlate;
+struct GenericPlate;
+
+const VALIDATORS: [&dyn PlateValidator; 4] =
+    [&DanishPlate, &GermanPlate, &SwedishPlate, &NorwegianPlate];
+
+pub fn plate_validator(country: &str) -> &'static dyn PlateValidator {
+    VALIDATORS
+        .iter()
+        .copied()
+        .find(|validator| validator.country() == country)
+        .unwrap_or(&GenericPlate)
+}
+
+pub fn normalize_plate(plate: &str) -> String {
+    plate
+        .chars()
+        .filter(|c| !matches!(c, ' ' | '-'))
+        .flat_map(char::to_uppercase)
+        .collect()
+}
+
+pub fn validate_vehicle(vehicle: &Vehicle) -> Result<()> {
+    if vehicle.plate.is_empty() {
+        return Err(AppError::Input("License plate cannot be empty".to_string()));
+    }
+
+    if vehicle.country.len() != 2 || !vehicle.country.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(AppError::Input(
+            "Country must be a 2 letter code, e.g. DK".to_string(),
+        ));
+    }
+
+    if !vehicle.plate.chars().all(is_plate_char) {
+        return Err(AppError::Input(
+            "License plate can only contain letters and digits".to_string(),
+        ));
+    }
+
+    plate_validator(&vehicle.country)
+        .validate(&vehicle.plate)
+        .map_err(AppError::Input)
+}
+
+fn is_plate_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "ÆØÅÄÖÜ".contains(c)
+}
+
+fn split_letters(plate: &str) -> (&str, &str) {
+    let index = plate
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(plate.len());
+    plate.split_at(index)
+}
+
+struct PersonalizedRule {
+    national_letters: &'static str,
+    regular_prefix: usize,
+}
+
+const DANISH_PERSONALIZED: PersonalizedRule = PersonalizedRule {
+    national_letters: "ÆØÅ",
+    regular_prefix: 2,
+};
+const SWEDISH_PERSONALIZED: PersonalizedRule = PersonalizedRule {
+    national_letters: "ÅÄÖ",
+    regular_prefix: 3,
+};
+const NORWEGIAN_PERSONALIZED: PersonalizedRule = PersonalizedRule {
+    national_letters: "ÆØÅ",
+    regular_prefix: 2,
+};
+
+impl PersonalizedRule {
+    // 2-7 letters and digits with at least one letter, which must not be
+    // mistakable for a regular plate (the regular letter prefix followed by digits only)
+    fn matches(&self, plate: &str) -> bool {
+        let is_letter = |c: char| c.is_ascii_uppercase() || self.national_letters.contains(c);
+        let (letters, digits) = split_letters(plate);
+
+        (2..=7).contains(&plate.chars().count())
+            && plate.chars().all(|c| is_letter(c) || c.is_ascii_digit())
+            && plate.chars().any(is_letter)
+            && !(letters.chars().count() == self.regular_prefix
+                && !digits.is_empty()
+                && digits.chars().all(|c| c.is_ascii_digit()))
+    }
+}
+
+impl PlateValidator for DanishPlate {
+    fn country(&self) -> &'static str {
+        "DK"
+    }
+
+    fn validate(&self, plate: &str) -> std::result::Result<(), String> {
+        let (letters, digits) = split_letters(plate);
+
+        let regular = letters.len() == 2
+            && letters.chars().all(|c| c.is_ascii_uppercase())
+            && digits.len() == 5
+            && digits.chars().all(|c| c.is_ascii_digit());
+
+        if !regular && !DANISH_PERSONALIZED.matches(plate) {
+            return Err(
+                "Danish plates look like AB12345, or 2-7 letters and digits if personalized"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl PlateValidator for GermanPlate {
+    fn country(&self) -> &'static str {
+        "DE"
+    }
+
+    fn validate(&self, plate: &str) -> std::result::Result<(), String> {
+        let plate = plate
+            .strip_suffix(['E', 'H'])
+            .filter(|rest| rest.ends_with(|c: char| c.is_ascii_digit()))
+            .unwrap_or(plate);
+        let (letters, digits) = split_letters(plate);
+
+        let valid = (2..=5).contains(&letters.chars().count())
+            && (1..=4).contains(&digits.len())
+            && !digits.starts_with('0')
+            && digits.chars().all(|c| c.is_ascii_digit())
+            && letters.chars().count() + digits.len() <= 8;
+
+        if !valid {
+            return Err("German plates are 2-5 letters and 1-4 digits, e.g. M-AB 1234".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl PlateValidator for SwedishPlate {
+    fn country(&self) -> &'static str {
+        "SE"
+    }
+
+    fn validate(&self, plate: &str) -> std::result::Result<(), String> {
+        let chars: Vec<char> = plate.chars().collect();
+
+        let regular = chars.len() == 6
+            && chars[..3].iter().all(|c| c.is_ascii_uppercase())
+            && chars[3..5].iter().all(|c| c.is_ascii_digit())
+            && (chars[5].is_ascii_digit() || chars[5].is_ascii_uppercase());
+
+        if !regular && !SWEDISH_PERSONALIZED.matches(plate) {
+            return Err(
+                "Swedish plates look like ABC123 or ABC12D, or 2-7 letters and digits if personalized"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl PlateValidator for NorwegianPlate {
+    fn country(&self) -> &'static str {
+        "NO"
+    }
+
+    fn validate(&self, plate: &str) -> std::result::Result<(), String> {
+        let (letters, digits) = split_letters(plate);
+
+        let regular = letters.len() == 2
+            && letters.chars().all(|c| c.is_ascii_uppercase())
+            && (4..=5).contains(&digits.len())
+            && digits.chars().all(|c| c.is_ascii_digit());
+
+        if !regular && !NORWEGIAN_PERSONALIZED.matches(plate) {
+            return Err(
+                "Norwegian plates look like AB12345, or 2-7 letters and digits if personalized"
+                    .to_string(),
+            );
+        }
+        Ok(())
+    }
+}
+
+impl PlateValidator for GenericPlate {
+    fn country(&self) -> &'static str {
+        ""
+    }
+
+    fn validate(&self, plate: &str) -> std::result::Result<(), String> {
+        if !(2..=10).contains(&plate.chars().count()) {
+            return Err("License plates must be 2-10 letters and digits".to_string());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check(country: &str, accepted: &[&str], rejected: &[&str]) {
+        let validator = plate_validator(country);
+
+        for plate in accepted {
+            assert!(validator.validate(plate).is_ok(), "{country} {plate}");
+        }
+        for plate in rejected {
+            assert!(validator.validate(plate).is_err(), "{country} {plate}");
+        }
+    }
+
+    #[test]
+    fn danish_plates() {
+        check(
+            "DK",
+            &["AB12345", "XY98765"],
+            &["AB1234", "AB123456", "AB12", "1234567"],
+        );
+        check(
+            "DK",
+            &["HELLO", "ÆBLE", "MOR1", "AB", "A1B2C3D", "ABC1234"],
+            &["ÄBLE", "HELLOWORLD", "A", "12"],
+        );
+    }
+
+    #[test]
+    fn german_plates() {
+        check(
+            "DE",
+            &["MAB1234", "BX1", "HHAB123", "MAB123E", "MAB12H"],
+            &[],
+        );
+        check(
+            "DE",
+            &[],
+            &["M1234", "MAB0123", "MAB12345", "ABCDEF1", "MAB"],
+        );
+    }
+
+    #[test]
+    fn swedish_plates() {
+        check("SE", &["ABC123", "ABC12D"], &["ABC1234", "ABC12", "ABC1"]);
+        check(
+            "SE",
+            &["HEJSAN", "ÅSA", "A1B2", "AB1234", "ABC1D3"],
+            &["ÆBLE", "ÄLG1", "ÅSA1", "12", "HEJSANSVEJSAN"],
+        );
+    }
+
+    #[test]
+    fn norwegian_plates() {
+        check("NO", &["AB12345", "EL1234"], &["AB123", "AB123456", "AB1"]);
+        check(
+            "NO",
+            &["ELBIL1", "ØL", "HEI", "ABC1234"],
+            &["ÄBLE", "1234", "ABCDEFGH"],
+        );
+    }
+
+    #[test]
+    fn other_countries_accept_2_to_10_characters() {
+        check(
+            "FR",
+            &["AB123CD", "12", "ABCDEFGHIJ"],
+            &["A", "ABCDEFGHIJK"],
+        );
+    }
+
+    #[test]
+    fn vehicles_are_checked_against_their_country() {
+        let vehicle = |plate: &str, country: &str| Vehicle {
+            plate: plate.to_string(),
+            country: country.to_string(),
+            nickname: None,
+        };
+
+        assert!(validate_vehicle(&vehicle("AB1234", "SE")).is_ok());
+        assert!(validate_vehicle(&vehicle("AB1234", "DK")).is_err());
+        assert!(validate_vehicle(&vehicle("", "DK")).is_err());
+        assert!(validate_vehicle(&vehicle("AB12345", "dk")).is_err());
+        assert!(validate_vehicle(&vehicle("AB 12345", "DK")).is_err());
+    }
+
+    #[test]
+    fn normalize_strips_spaces_and_hyphens() {
+        assert_eq!(normalize_plate("m-ab 1234"), "MAB1234");
+    }
+}
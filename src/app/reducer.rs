@@ -0,0 +1,752 @@
+use chrono::Local;
+
+use crate::app::action::Action;
+use crate::app::effects::Effect;
+use crate::app::modals::{
+    cart::CartModalActions, error::ErrorModalActions, history::HistoryModalActions,
+    parking::ParkingModalActions, parking_log::ParkingLogModalActions,
+    purchase::PurchaseModalActions, quick_buy::QuickBuyModalActions, search::SearchModalActions,
+    stats::StatsModalActions, terminal_size::TerminalSizeModalActions,
+    username::UsernameModalActions,
+};
+use crate::app::state::{AppState, InputMode};
+use crate::app::tasks::{PurchaseRequest, PurchaseTarget, TaskKind};
+
+pub fn reduce(state: &mut AppState, action: Action) -> Vec<Effect> {
+    let effects = apply(state, action);
+
+    for effect in &effects {
+        match effect {
+            Effect::Cancel(kind) => {
+                state.tasks.running.remove(kind);
+            }
+            Effect::CancelAll => state.tasks.running.clear(),
+            effect => {
+                if let Some(kind) = effect.task_kind() {
+                    state.tasks.running.insert(kind);
+                }
+            }
+        }
+    }
+
+    effects
+}
+
+fn apply(state: &mut AppState, action: Action) -> Vec<Effect> {
+    let has_username = state.config.username().is_some();
+
+    match action {
+        Action::Start => {
+            if !has_username {
+                state.ui.base_mode = InputMode::Editing;
+            }
+
+            return state.refresh_effects(Local::now());
+        }
+        Action::Quit => {
+            state.should_quit = true;
+            return vec![Effect::CancelAll];
+        }
+        Action::Tick => {
+            state.tasks.spinner_frame = state.tasks.spinner_frame.wrapping_add(1);
+
+            let now = Local::now();
+            let mut effects: Vec<Effect> =
+                state.scheduled_parking_effect(now).into_iter().collect();
+            effects.extend(state.auto_refresh_effects(now));
+            return effects;
+        }
+        Action::Refresh => {
+            return state.refresh_effects(Local::now());
+        }
+        Action::Resize { width, height } => {
+            state.ui.terminal_height = height;
+            state.check_terminal_size(width, height);
+        }
+        Action::TaskCompleted(result) => {
+            state.tasks.running.remove(&result.kind());
+            return state.apply_task_result(result);
+        }
+        Action::ConfigSaveFailed(message) => {
+            state.show_error_modal(
+                &format!("Failed to save config: {message}"),
+                Some("Config Error"),
+            );
+        }
+        Action::ParkingLogFailed(message) => {
+            state.show_error_modal(
+                &format!("Failed to save parking log: {message}"),
+                Some("Parking Error"),
+            );
+        }
+        Action::LedgerFailed(message) => {
+            state.show_error_modal(
+                &format!("Failed to save purchase ledger: {message}"),
+                Some("Ledger Error"),
+            );
+        }
+        Action::CancelLoading => {
+            state.ui.number_prefix.clear();
+            state.ui.pending_g = false;
+
+            let mut effects = Vec::new();
+
+            if state.tasks.is_running(TaskKind::Products) {
+                if state.products.items.is_empty() {
+                    state.products.error = Some("Loading products was cancelled".to_string());
+                }
+                effects.push(Effect::Cancel(TaskKind::Products));
+            }
+
+            if state.tasks.is_running(TaskKind::User) {
+                if state.user.member_info.is_none() {
+                    state.user.error = Some("Loading user data was cancelled".to_string());
+                }
+                effects.push(Effect::Cancel(TaskKind::User));
+            }
+
+            return effects;
+        }
+
+        Action::MoveDown => {
+            let count = state.take_count();
+            state.move_products_down(count);
+            state.ui.pending_g = false;
+        }
+        Action::MoveUp => {
+            let count = state.take_count();
+            state.move_products_up(count);
+            state.ui.pending_g = false;
+        }
+        Action::PressG => {
+            if state.ui.pending_g {
+                state.go_to_top();
+                state.ui.pending_g = false;
+            } else {
+                state.ui.pending_g = true;
+            }
+        }
+        Action::GoToBottom => {
+            state.go_to_bottom();
+        }
+        Action::PushCountDigit(c) => {
+            state.ui.number_prefix.push(c);
+
+            if state.get_movement_target_indices().is_empty() {
+                state.ui.number_prefix.clear();
+            }
+
+            state.ui.pending_g = false;
+        }
+        Action::AddSelectedToCart if has_username => {
+            state.add_selected_product_to_cart();
+        }
+        Action::ToggleFavorite => {
+            state.ui.pending_g = false;
+
+            if state.toggle_selected_favorite().is_some() {
+                return vec![Effect::SaveConfig];
+            }
+        }
+        Action::NextFavorite => {
+            state.select_next_favorite();
+            state.ui.pending_g = false;
+        }
+        Action::OpenPurchase if has_username && !state.products.items.is_empty() => {
+            state.show_purchase_modal();
+        }
+        Action::OpenUsername if has_username => {
+            state.show_username_modal();
+        }
+        Action::OpenSearch => {
+            state.show_search_modal();
+        }
+        Action::OpenParking => {
+            state.show_parking_modal();
+        }
+        Action::OpenParkingLog => {
+            state.show_parking_log_modal();
+        }
+        Action::OpenCart if has_username => {
+            state.show_cart_modal();
+        }
+        Action::OpenQuickBuy if has_username => {
+            state.show_quick_buy_modal();
+        }
+        Action::OpenHistory if has_username => {
+            state.show_history_modal();
+        }
+        Action::OpenStats if has_username => {
+            state.show_stats_modal();
+        }
+
+        Action::LoginInput(c) => {
+            state.ui.input.push(c);
+        }
+        Action::LoginBackspace => {
+            state.ui.input.pop();
+        }
+        Action::SubmitLogin if !state.ui.input.trim().is_empty() => {
+            let username = state.ui.input.trim().to_string();
+            state.config.set_username(username);
+            state.ui.base_mode = InputMode::Normal;
+
+            return reload_user(state);
+        }
+        Action::CancelLogin if has_username => {
+            state.ui.base_mode = InputMode::Normal;
+        }
+
+        Action::UsernameInput(c) => {
+            state.modals.username.input.push(c);
+        }
+        Action::UsernameBackspace => {
+            state.modals.username.input.pop();
+        }
+        Action::SubmitUsername => {
+            if let Err(e) = state.update_username() {
+                state.show_error_modal(
+                    &format!("Error updating username: {e}"),
+                    Some("Username Update Error"),
+                );
+            } else {
+                return reload_user(state);
+            }
+        }
+        Action::CloseUsername => {
+            state.hide_username_modal();
+        }
+
+        Action::IncreaseQuantity => {
+            state.increase_quantity();
+        }
+        Action::DecreaseQuantity => {
+            state.decrease_quantity();
+        }
+        Action::ConfirmPurchase => {
+            return purchase(PurchaseTarget::Single, state.begin_purchase());
+        }
+        Action::ClosePurchase => {
+            state.hide_purchase_modal();
+        }
+        Action::CancelPurchase(target) => {
+            state.cancel_pending_purchase(target);
+
+            let mut effects = vec![Effect::Cancel(TaskKind::Purchase)];
+            effects.extend(state.load_user_effect());
+            return effects;
+        }
+
+        Action::NextCartLine => {
+            state.next_cart_line();
+        }
+        Action::PreviousCartLine => {
+            state.previous_cart_line();
+        }
+        Action::IncreaseCartQuantity => {
+            state.increase_cart_quantity();
+        }
+        Action::DecreaseCartQuantity => {
+            state.decrease_cart_quantity();
+        }
+        Action::RemoveCartLine => {
+            state.remove_cart_line();
+        }
+        Action::BuyCart => {
+            return purchase(PurchaseTarget::Cart, state.begin_cart_purchase());
+        }
+        Action::CloseCart => {
+            state.hide_cart_modal();
+        }
+
+        Action::QuickBuyInput(c) => {
+            state.modals.quick_buy.input.push(c);
+            state.update_quick_buy_preview();
+        }
+        Action::QuickBuyBackspace => {
+            state.modals.quick_buy.input.pop();
+            state.update_quick_buy_preview();
+        }
+        Action::ReviewQuickBuy => {
+            state.confirm_quick_buy();
+        }
+        Action::EditQuickBuy => {
+            state.cancel_quick_buy_confirmation();
+        }
+        Action::ConfirmQuickBuy => {
+            return purchase(PurchaseTarget::QuickBuy, state.begin_quick_buy());
+        }
+        Action::CloseQuickBuy => {
+            state.hide_quick_buy_modal();
+        }
+
+        Action::SearchInput(c) => {
+            state.modals.search.input.push(c);
+            state.update_search_results();
+        }
+        Action::SearchBackspace => {
+            state.modals.search.input.pop();
+            state.update_search_results();
+        }
+        Action::NextSearchResult => {
+            state.next_search_result();
+        }
+        Action::PreviousSearchResult => {
+            state.previous_search_result();
+        }
+        Action::SelectSearchResult => {
+            state.select_product_from_search();
+        }
+        Action::AddSearchResultToCart => {
+            state.add_search_result_to_cart();
+        }
+        Action::CloseSearch => {
+            state.hide_search_modal();
+        }
+
+        Action::ParkingInput(c) => {
+            state.parking_editor_input(c);
+        }
+        Action::ParkingBackspace => {
+            state.parking_editor_backspace();
+        }
+        Action::NextParkingField if state.modals.parking.editor.is_some() => {
+            state.next_parking_editor_field();
+        }
+        Action::NextParkingField => {
+            state.next_parking_field();
+        }
+        Action::PreviousParkingField => {
+            state.prev_parking_field();
+        }
+        Action::NextParkingProfile => {
+            state.next_parking_profile();
+        }
+        Action::PreviousParkingProfile => {
+            state.prev_parking_profile();
+        }
+        Action::NextParkingEntry => {
+            state.next_parking_entry();
+        }
+        Action::PreviousParkingEntry => {
+            state.previous_parking_entry();
+        }
+        Action::AddParkingEntry => {
+            state.add_parking_entry();
+        }
+        Action::EditParkingEntry => {
+            state.edit_parking_entry();
+        }
+        Action::DeleteParkingEntry => {
+            if state.delete_parking_entry() {
+                return vec![Effect::SaveConfig];
+            }
+        }
+        Action::SaveParkingEntry => match state.save_parking_entry() {
+            Ok(()) => return vec![Effect::SaveConfig],
+            Err(e) => {
+                state.show_error_modal(&e.to_string(), Some("Parking Error"));
+            }
+        },
+        Action::CancelParkingEdit => {
+            state.cancel_parking_edit();
+        }
+        Action::SubmitParking => match state.confirm_parking() {
+            Ok(()) => return vec![Effect::SaveConfig],
+            Err(e) => {
+                state.show_error_modal(
+                    &format!("Error confirming parking: {e}"),
+                    Some("Parking Error"),
+                );
+            }
+        },
+        Action::ConfirmParking => {
+            if let Some(request) = state.begin_parking_registration() {
+                return vec![Effect::RegisterParking(request)];
+            }
+        }
+        Action::CancelParking => {
+            state.cancel_pending_parking();
+            return vec![Effect::Cancel(TaskKind::Parking)];
+        }
+        Action::CloseParking => {
+            state.hide_parking_modal();
+        }
+
+        Action::NextParkingLogEntry => {
+            state.next_parking_log_entry();
+        }
+        Action::PreviousParkingLogEntry => {
+            state.previous_parking_log_entry();
+        }
+        Action::CloseParkingLog => {
+            state.hide_parking_log_modal();
+        }
+
+        Action::ScrollHistoryDown => {
+            state.scroll_history_down(1);
+        }
+        Action::ScrollHistoryUp => {
+            state.scroll_history_up(1);
+        }
+        Action::PageHistoryDown => {
+            state.scroll_history_down(state.history_page_size());
+        }
+        Action::PageHistoryUp => {
+            state.scroll_history_up(state.history_page_size());
+        }
+        Action::HistoryTop => {
+            state.modals.history.scroll = 0;
+        }
+        Action::HistoryBottom => {
+            state.scroll_history_down(usize::MAX);
+        }
+        Action::EditHistoryFilter(field) => {
+            state.edit_history_field(field);
+        }
+        Action::NextHistoryField => {
+            state.next_history_field();
+        }
+        Action::HistoryInput(c) => {
+            state.history_input(c);
+        }
+        Action::HistoryBackspace => {
+            state.history_backspace();
+        }
+        Action::FinishHistoryEdit => {
+            state.finish_history_edit();
+        }
+        Action::ClearHistoryFilters => {
+            state.clear_history_filters();
+        }
+        Action::CloseHistory => {
+            state.hide_history_modal();
+        }
+
+        Action::NextStatsPeriod => {
+            state.modals.stats.period = state.modals.stats.period.next();
+        }
+        Action::PreviousStatsPeriod => {
+            state.modals.stats.period = state.modals.stats.period.previous();
+        }
+        Action::SetStatsPeriod(period) => {
+            state.modals.stats.period = period;
+        }
+        Action::CloseStats => {
+            state.hide_stats_modal();
+        }
+
+        Action::CloseErrorModal => {
+            state.hide_error_modal();
+        }
+
+        Action::AddSelectedToCart
+        | Action::OpenPurchase
+        | Action::OpenUsername
+        | Action::OpenCart
+        | Action::OpenQuickBuy
+        | Action::OpenHistory
+        | Action::OpenStats
+        | Action::SubmitLogin
+        | Action::CancelLogin => {}
+    }
+
+    Vec::new()
+}
+
+fn purchase(target: PurchaseTarget, request: Option<PurchaseRequest>) -> Vec<Effect> {
+    request
+        .map(|request| Effect::Purchase { target, request })
+        .into_iter()
+        .collect()
+}
+
+fn reload_user(state: &mut AppState) -> Vec<Effect> {
+    state.user.member_id = None;
+    state.user.member_info = None;
+    state.user.latest_sales = Vec::new();
+    state.user.error = None;
+
+    let mut effects = vec![Effect::SaveConfig];
+    effects.extend(state.login_effect());
+    effects
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use tokio::sync::mpsc;
+
+    use super::*;
+    use crate::api::fake::{FakeApi, FakeEndpoint};
+    use crate::app::effects::EffectRunner;
+    use crate::app::state::HistoryField;
+    use crate::config::settings::Config;
+    use crate::models::parking::Vehicle;
+    use crate::models::sale::Sale;
+    use crate::ui::events::UIEvent;
+    use crate::utils::money::Money;
+
+    struct Harness {
+        state: AppState,
+        api: Arc<FakeApi>,
+        effects: EffectRunner,
+        rx: mpsc::Receiver<UIEvent>,
+    }
+
+    impl Harness {
+        async fn start(api: FakeApi, config: Config) -> Self {
+            let api = Arc::new(api);
+            let (tx, rx) = mpsc::channel(16);
+
+            let mut harness = Self {
+                state: AppState::new(config),
+                effects: EffectRunner::new(api.clone(), tx),
+                api,
+                rx,
+            };
+            harness.dispatch(Action::Start).await;
+            harness
+        }
+
+        async fn logged_in(api: FakeApi, username: &str) -> Self {
+            let mut config = Config::default();
+            config.set_username(username.to_string());
+            Self::start(api, config).await
+        }
+
+        async fn dispatch(&mut self, action: Action) {
+            let mut effects = reduce(&mut self.state, action);
+
+            loop {
+                for effect in effects.drain(..) {
+                    self.effects.run(effect);
+                }
+
+                if self.state.tasks.running.is_empty() {
+                    break;
+                }
+
+                if let Some(UIEvent::Task { id, result }) = self.rx.recv().await {
+                    if self.effects.finish(id, result.kind()) {
+                        effects = reduce(&mut self.state, Action::TaskCompleted(result));
+                    }
+                }
+            }
+        }
+
+        async fn type_text(&mut self, text: &str, action: fn(char) -> Action) {
+            for c in text.chars() {
+                self.dispatch(action(c)).await;
+            }
+        }
+    }
+
+    fn shop() -> FakeApi {
+        FakeApi::new()
+            .with_product("11", "Cola", Money::new(1000))
+            .with_product("14", "Beer", Money::new(1500))
+            .with_named_product("beer", 14)
+            .with_member("alice", "Alice", Money::new(10000))
+    }
+
+    #[tokio::test]
+    async fn login_loads_the_member_and_their_sales() {
+        let api = shop().with_sale(
+            "alice",
+            Sale {
+                timestamp: "2025-01-01T12:00:00+01:00".to_string(),
+                product: "Cola".to_string(),
+                price: Money::new(1000),
+            },
+        );
+        let mut harness = Harness::start(api, Config::default()).await;
+        assert_eq!(harness.state.ui.base_mode, InputMode::Editing);
+
+        harness.type_text("alice", Action::LoginInput).await;
+        harness.dispatch(Action::SubmitLogin).await;
+
+        let state = &harness.state;
+        assert_eq!(state.ui.base_mode, InputMode::Normal);
+        assert_eq!(state.config.username().map(String::as_str), Some("alice"));
+        assert_eq!(
+            state.user.member_info.as_ref().map(|info| info.balance),
+            Some(Money::new(10000))
+        );
+        assert_eq!(state.user.latest_sales.len(), 1);
+        assert_eq!(state.ledger_sales().len(), 1);
+        assert_eq!(state.products.items.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn login_with_an_unknown_username_shows_an_error() {
+        let mut harness = Harness::start(shop(), Config::default()).await;
+
+        harness.type_text("mallory", Action::LoginInput).await;
+        harness.dispatch(Action::SubmitLogin).await;
+
+        assert!(harness.state.user.member_info.is_none());
+        assert!(harness.state.user.error.is_some());
+        assert!(harness.state.modals.error.visible);
+    }
+
+    #[tokio::test]
+    async fn offline_refresh_keeps_the_username_without_a_modal() {
+        let api = shop().failing(FakeEndpoint::MemberId);
+        let mut harness = Harness::logged_in(api, "alice").await;
+        harness.dispatch(Action::Refresh).await;
+
+        let state = &harness.state;
+        assert!(!state.modals.error.visible);
+        assert!(state.user.error.is_some());
+        assert_eq!(state.config.username().map(String::as_str), Some("alice"));
+    }
+
+    #[tokio::test]
+    async fn failed_login_shows_the_username_error() {
+        let api = shop().failing(FakeEndpoint::MemberId);
+        let mut harness = Harness::start(api, Config::default()).await;
+
+        harness.type_text("alice", Action::LoginInput).await;
+        harness.dispatch(Action::SubmitLogin).await;
+
+        assert!(harness.state.modals.error.visible);
+        assert_eq!(
+            harness.state.modals.error.title.as_deref(),
+            Some("Username Error")
+        );
+    }
+
+    #[tokio::test]
+    async fn failing_products_endpoint_is_reported() {
+        let api = shop().failing(FakeEndpoint::Products);
+        let harness = Harness::logged_in(api, "alice").await;
+
+        assert!(harness.state.products.items.is_empty());
+        assert!(harness.state.products.error.is_some());
+    }
+
+    #[tokio::test]
+    async fn search_selects_the_product_in_the_list() {
+        let mut harness = Harness::logged_in(shop(), "alice").await;
+
+        harness.dispatch(Action::OpenSearch).await;
+        harness.type_text("beer", Action::SearchInput).await;
+        assert_eq!(harness.state.modals.search.results.len(), 1);
+
+        harness.dispatch(Action::SelectSearchResult).await;
+
+        assert!(!harness.state.modals.search.visible);
+        assert_eq!(harness.state.selected_product_id().as_deref(), Some("14"));
+    }
+
+    #[tokio::test]
+    async fn purchase_charges_the_member() {
+        let mut harness = Harness::logged_in(shop(), "alice").await;
+
+        harness.dispatch(Action::OpenSearch).await;
+        harness.type_text("cola", Action::SearchInput).await;
+        harness.dispatch(Action::SelectSearchResult).await;
+        harness.dispatch(Action::OpenPurchase).await;
+        harness.dispatch(Action::IncreaseQuantity).await;
+        harness.dispatch(Action::ConfirmPurchase).await;
+
+        let state = &harness.state;
+        assert!(state.modals.purchase.success);
+        assert_eq!(harness.api.balance_of("alice"), Some(Money::new(8000)));
+        assert_eq!(
+            state.user.member_info.as_ref().map(|info| info.balance),
+            Some(Money::new(8000))
+        );
+        assert_eq!(state.ledger_sales().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn failed_purchase_keeps_the_balance() {
+        let mut harness = Harness::logged_in(shop(), "alice").await;
+        harness.api.set_failing(FakeEndpoint::Purchase, true);
+
+        harness.dispatch(Action::OpenPurchase).await;
+        harness.dispatch(Action::ConfirmPurchase).await;
+
+        let error = harness
+            .state
+            .modals
+            .purchase
+            .error
+            .clone()
+            .unwrap_or_default();
+        assert!(error.starts_with("Purchase failed"));
+        assert_eq!(harness.api.balance_of("alice"), Some(Money::new(10000)));
+    }
+
+    #[tokio::test]
+    async fn quick_buy_resolves_named_products() {
+        let mut harness = Harness::logged_in(shop(), "alice").await;
+
+        harness.dispatch(Action::OpenQuickBuy).await;
+        harness.type_text("beer:2 11", Action::QuickBuyInput).await;
+        harness.dispatch(Action::ReviewQuickBuy).await;
+        harness.dispatch(Action::ConfirmQuickBuy).await;
+
+        assert!(harness.state.modals.quick_buy.success);
+        assert_eq!(harness.api.balance_of("alice"), Some(Money::new(6000)));
+    }
+
+    #[tokio::test]
+    async fn cart_with_an_unavailable_product_is_not_bought() {
+        let mut harness = Harness::logged_in(shop(), "alice").await;
+
+        harness.state.add_to_cart("11");
+        harness.state.add_to_cart("14");
+        harness.state.products.items.remove("14");
+
+        harness.dispatch(Action::OpenCart).await;
+        harness.dispatch(Action::BuyCart).await;
+
+        assert!(harness.state.modals.cart.error.is_some());
+        assert_eq!(harness.state.cart.lines.len(), 2);
+        assert_eq!(harness.api.balance_of("alice"), Some(Money::new(10000)));
+    }
+
+    #[tokio::test]
+    async fn invalid_history_date_keeps_the_filter_active() {
+        let mut harness = Harness::logged_in(shop(), "alice").await;
+
+        harness.dispatch(Action::OpenHistory).await;
+        harness
+            .dispatch(Action::EditHistoryFilter(HistoryField::From))
+            .await;
+        harness.type_text("2026-13-01", Action::HistoryInput).await;
+
+        assert!(harness.state.history_filter().invalid_date);
+
+        harness.dispatch(Action::ClearHistoryFilters).await;
+        assert!(!harness.state.history_filter().invalid_date);
+    }
+
+    #[tokio::test]
+    async fn parking_registers_the_selected_vehicle() {
+        let config = Config {
+            phone_numbers: vec!["12345678".to_string()],
+            vehicles: vec![Vehicle {
+                plate: "AB12345".to_string(),
+                country: "DK".to_string(),
+                nickname: None,
+            }],
+            ..Config::default()
+        };
+        let mut harness = Harness::start(shop(), config).await;
+
+        harness.dispatch(Action::OpenParking).await;
+        harness.dispatch(Action::SubmitParking).await;
+        harness.dispatch(Action::ConfirmParking).await;
+
+        let registrations = harness.api.parking_registrations();
+        assert_eq!(registrations.len(), 1);
+        assert_eq!(registrations[0].vehicle_registration, "AB12345");
+        assert!(harness.state.modals.parking.success);
+        assert_eq!(harness.state.parking_log.len(), 1);
+    }
+}
@@ -0,0 +1,63 @@
+use chrono::{DateTime, Local};
+
+use crate::app::effects::Effect;
+use crate::app::state::AppState;
+use crate::app::tasks::TaskKind;
+
+impl AppState {
+    pub fn refresh_effects(&mut self, now: DateTime<Local>) -> Vec<Effect> {
+        self.last_refresh = Some(now);
+
+        let mut effects = Vec::new();
+
+        if !self.tasks.is_running(TaskKind::Products) {
+            effects.push(Effect::LoadProducts);
+        }
+
+        if !self.tasks.is_running(TaskKind::User) && !self.tasks.is_running(TaskKind::Purchase) {
+            effects.extend(self.load_user_effect());
+        }
+
+        effects
+    }
+
+    pub fn auto_refresh_effects(&mut self, now: DateTime<Local>) -> Vec<Effect> {
+        let Some(interval) = self.config.network.refresh_interval() else {
+            return Vec::new();
+        };
+
+        let due = match self.last_refresh {
+            Some(last_refresh) => (now - last_refresh)
+                .to_std()
+                .map_or(true, |elapsed| elapsed >= interval),
+            None => true,
+        };
+
+        if due {
+            self.refresh_effects(now)
+        } else {
+            Vec::new()
+        }
+    }
+
+    pub fn selected_product_id(&self) -> Option<String> {
+        let selected = self.products.list_state.selected()?;
+        Some(self.get_sorted_products().get(selected)?.id.clone())
+    }
+
+    pub fn reselect_product(&mut self, product_id: Option<String>) {
+        let products = self.get_sorted_products();
+        if products.is_empty() {
+            return;
+        }
+
+        let index = product_id
+            .and_then(|id| products.iter().position(|product| product.id == id))
+            .unwrap_or_else(|| {
+                let selected = self.products.list_state.selected().unwrap_or(0);
+                selected.min(products.len() - 1)
+            });
+
+        self.products.list_state.select(Some(index));
+    }
+}
@@ -0,0 +1,212 @@
+use std::path::PathBuf;
+use std::process::Stdio;
+
+use chrono::{DateTime, Local};
+use tokio::process::Command;
+
+use crate::api::backend::StregApi;
+use crate::app::effects::Effect;
+use crate::app::state::AppState;
+use crate::app::tasks::TaskKind;
+use crate::config::parking_log::{read_parking_log, write_parking_log};
+use crate::config::settings::Config;
+use crate::error::{AppError, Result};
+use crate::models::parking::{ParkingPermitRequest, ParkingRegistration, Vehicle};
+
+#[derive(Debug, Clone)]
+pub struct ScheduledParking {
+    pub slot: DateTime<Local>,
+    pub profile: String,
+    pub request: ParkingPermitRequest,
+    pub hook: Option<String>,
+    pub log_path: Option<PathBuf>,
+}
+
+#[derive(Debug, Clone)]
+pub enum ScheduleOutcome {
+    Registered(ParkingRegistration),
+    AlreadyRegistered(ParkingRegistration),
+}
+
+impl ScheduleOutcome {
+    pub fn registration(&self) -> &ParkingRegistration {
+        match self {
+            ScheduleOutcome::Registered(registration)
+            | ScheduleOutcome::AlreadyRegistered(registration) => registration,
+        }
+    }
+
+    pub fn message(&self) -> String {
+        let registration = self.registration();
+        let expires_at = registration.expires_at().format("%d/%m %H:%M");
+
+        match self {
+            ScheduleOutcome::Registered(_) => match registration.permit.warning() {
+                Some(warning) => format!(
+                    "Registered parking for {} in {} until {expires_at}. {warning}",
+                    registration.plate, registration.profile
+                ),
+                None => format!(
+                    "Registered parking for {} in {} until {expires_at}",
+                    registration.plate, registration.profile
+                ),
+            },
+            ScheduleOutcome::AlreadyRegistered(_) => format!(
+                "{} is already parked until {expires_at}",
+                registration.plate
+            ),
+        }
+    }
+}
+
+pub fn due_parking(
+    config: &Config,
+    log: &[ParkingRegistration],
+    now: DateTime<Local>,
+) -> Option<ScheduledParking> {
+    let schedule = config.parking_schedule.as_ref()?;
+    let slot = schedule.latest_slot(now)?;
+
+    let profiles = config.parking_profiles();
+    let profile = match schedule.profile {
+        Some(ref name) => profiles.iter().find(|profile| &profile.name == name)?,
+        None => profiles.get(config.selected_parking_profile())?,
+    };
+
+    let plate = schedule
+        .vehicle
+        .as_ref()
+        .or(config.license_plate.as_ref().filter(|plate| {
+            config
+                .vehicles
+                .iter()
+                .any(|vehicle| &vehicle.plate == *plate)
+        }))
+        .or(config.vehicles.first().map(|vehicle| &vehicle.plate))?;
+    let vehicle = config
+        .vehicles
+        .iter()
+        .find(|vehicle| &vehicle.plate == plate)
+        .cloned()
+        .unwrap_or_else(|| Vehicle {
+            plate: plate.clone(),
+            country: profile.country.clone(),
+            nickname: None,
+        });
+
+    let phone_number = schedule
+        .phone_number
+        .as_ref()
+        .or(config
+            .phone_number
+            .as_ref()
+            .filter(|number| config.phone_numbers.contains(number)))
+        .or(config.phone_numbers.first())?;
+
+    if covering_registration(log, &vehicle.plate, slot, now).is_some() {
+        return None;
+    }
+
+    Some(ScheduledParking {
+        slot,
+        profile: profile.name.clone(),
+        request: profile.permit_request(&vehicle, phone_number),
+        hook: schedule.hook.clone(),
+        log_path: if config.demo {
+            None
+        } else {
+            config.parking_log_path().ok()
+        },
+    })
+}
+
+fn covering_registration<'a>(
+    log: &'a [ParkingRegistration],
+    plate: &str,
+    slot: DateTime<Local>,
+    now: DateTime<Local>,
+) -> Option<&'a ParkingRegistration> {
+    log.iter().rev().find(|registration| {
+        registration.plate == plate
+            && (registration.registered_at >= slot || registration.remaining(now).is_some())
+    })
+}
+
+pub async fn register_scheduled_parking(
+    api_client: &dyn StregApi,
+    scheduled: &ScheduledParking,
+) -> Result<ScheduleOutcome> {
+    // The daemon and the TUI may both be running, so check the log on disk again
+    if let Some(ref log_path) = scheduled.log_path {
+        let log = read_parking_log(log_path)?;
+        let plate = &scheduled.request.vehicle_registration;
+
+        if let Some(registration) = covering_registration(&log, plate, scheduled.slot, Local::now())
+        {
+            return Ok(ScheduleOutcome::AlreadyRegistered(registration.clone()));
+        }
+    }
+
+    let permit = api_client.register_parking(&scheduled.request).await?;
+    let registration = ParkingRegistration::new(&scheduled.profile, &scheduled.request, permit);
+
+    if let Some(ref log_path) = scheduled.log_path {
+        write_parking_log(log_path, &registration)?;
+    }
+
+    Ok(ScheduleOutcome::Registered(registration))
+}
+
+pub async fn run_parking_hook(
+    scheduled: &ScheduledParking,
+    result: &Result<ScheduleOutcome>,
+) -> Result<()> {
+    let Some(ref hook) = scheduled.hook else {
+        return Ok(());
+    };
+
+    let (status, message) = match result {
+        Ok(ScheduleOutcome::AlreadyRegistered(_)) | Err(AppError::DryRun(_)) => return Ok(()),
+        Ok(outcome) => ("registered", outcome.message()),
+        Err(e) => ("failed", format!("Scheduled parking failed: {e}")),
+    };
+
+    let exit_status = Command::new("sh")
+        .arg("-c")
+        .arg(hook)
+        .env("STUI_PARKING_STATUS", status)
+        .env(
+            "STUI_PARKING_PLATE",
+            &scheduled.request.vehicle_registration,
+        )
+        .env("STUI_PARKING_MESSAGE", message)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await?;
+
+    if !exit_status.success() {
+        return Err(AppError::Config(format!(
+            "Parking hook exited with {exit_status}"
+        )));
+    }
+
+    Ok(())
+}
+
+impl AppState {
+    pub fn scheduled_parking_effect(&mut self, now: DateTime<Local>) -> Option<Effect> {
+        if self.tasks.is_running(TaskKind::ScheduledParking) {
+            return None;
+        }
+
+        let scheduled = due_parking(&self.config, &self.parking_log, now)?;
+        if self.last_scheduled_slot == Some(scheduled.slot) {
+            return None;
+        }
+
+        self.last_scheduled_slot = Some(scheduled.slot);
+        Some(Effect::ScheduledParking(scheduled))
+    }
+}
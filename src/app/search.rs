@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+
+use crate::models::product::Product;
+
+pub fn search_products(
+    items: &HashMap<String, Product>,
+    named_products: &HashMap<String, i32>,
+    input: &str,
+) -> Vec<Product> {
+    let query = input.trim().to_lowercase();
+
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut results = Vec::new();
+
+    if let Ok(id) = query.parse::<i32>() {
+        let id_str = id.to_string();
+        if let Some(product) = items.get(&id_str) {
+            results.push(product.clone());
+        }
+    }
+
+    if query.chars().all(|c| c.is_ascii_digit()) && results.is_empty() {
+        for (id, product) in items {
+            if id.starts_with(&query) {
+                results.push(product.clone());
+            }
+        }
+    }
+
+    if results.len() < 10 {
+        let mut named_matches = Vec::new();
+
+        for (search_term, product_id) in named_products {
+            let search_term_lower = search_term.to_lowercase();
+
+            if search_term_lower.contains(&query) {
+                let id_str = product_id.to_string();
+
+                if let Some(product) = items.get(&id_str) {
+                    if !results
+                        .iter()
+                        .any(|result_product| result_product.id == id_str)
+                    {
+                        let relevance_score = if search_term_lower == query {
+                            1000
+                        } else if search_term_lower.starts_with(&query) {
+                            800
+                        } else {
+                            500 - (search_term_lower.len() as i32 - query.len() as i32).abs()
+                        };
+
+                        named_matches.push((id_str.clone(), product.clone(), relevance_score));
+                    }
+                }
+            }
+        }
+
+        named_matches.sort_by_key(|m| std::cmp::Reverse(m.2));
+
+        for (id, product, _) in named_matches {
+            if !results.iter().any(|result_product| result_product.id == id) {
+                results.push(product);
+
+                if results.len() >= 10 {
+                    break;
+                }
+            }
+        }
+    }
+
+    if results.len() < 10 {
+        let mut name_matches = Vec::new();
+
+        for (id, product) in items {
+            let product_name = product.name.to_lowercase();
+
+            if product_name.contains(&query)
+                && !results
+                    .iter()
+                    .any(|result_product| result_product.id == *id)
+            {
+                let relevance_score = if product_name.starts_with(&query) {
+                    700
+                } else {
+                    400 - (product_name.len() as i32 - query.len() as i32).abs()
+                };
+
+                name_matches.push((id.clone(), product.clone(), relevance_score));
+            }
+        }
+
+        name_matches.sort_by_key(|m| std::cmp::Reverse(m.2));
+
+        for (id, product, _) in name_matches {
+            if !results.iter().any(|result_product| result_product.id == id) {
+                results.push(product);
+
+                if results.len() >= 10 {
+                    break;
+                }
+            }
+        }
+    }
+
+    results.truncate(10);
+
+    results
+}
@@ -1,10 +1,19 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Local};
 use ratatui::widgets::ListState;
 
+use crate::app::buystring::{format_buystring, BuyItem};
+use crate::app::stats::StatsPeriod;
+use crate::app::tasks::{PurchaseRequest, TaskState};
 use crate::config::settings::Config;
+use crate::models::ledger::LedgerEntry;
 use crate::models::member::{MemberInfo, Sale};
+use crate::models::parking::ParkingRegistration;
+use crate::models::permit::ParkingPermit;
 use crate::models::product::Product;
+use crate::models::purchase::PurchaseResponse;
+use crate::utils::money::Money;
 
 #[derive(Clone)]
 pub struct AppState {
@@ -12,17 +21,30 @@ pub struct AppState {
     pub ui: UiState,
     pub products: ProductsState,
     pub user: UserState,
+    pub cart: CartState,
     pub modals: ModalState,
+    pub tasks: TaskState,
+    pub parking_log: Vec<ParkingRegistration>,
+    pub ledger: Vec<LedgerEntry>,
+    pub last_scheduled_slot: Option<DateTime<Local>>,
+    pub last_refresh: Option<DateTime<Local>>,
     pub should_quit: bool,
 }
 
 #[derive(Clone)]
 pub struct UiState {
     pub input: String,
-    pub input_mode: InputMode,
-    pub previous_input_mode: Option<InputMode>,
+    pub base_mode: InputMode,
+    pub modal_stack: Vec<InputMode>,
     pub number_prefix: String,
     pub pending_g: bool,
+    pub terminal_height: u16,
+}
+
+impl UiState {
+    pub fn input_mode(&self) -> InputMode {
+        self.modal_stack.last().copied().unwrap_or(self.base_mode)
+    }
 }
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -34,8 +56,12 @@ pub enum InputMode {
     SearchMode,
     ErrorModal,
     ParkingModal,
-    ParkingConfirmation,
+    ParkingLogModal,
     TerminalSizeModal,
+    CartModal,
+    QuickBuyModal,
+    HistoryModal,
+    StatsModal,
 }
 
 #[derive(Clone)]
@@ -45,6 +71,8 @@ pub struct ProductsState {
     pub error: Option<String>,
     pub named_products: HashMap<String, i32>,
     pub named_products_error: Option<String>,
+    pub updated_at: Option<DateTime<Local>>,
+    pub stale: bool,
 }
 
 #[derive(Clone)]
@@ -53,6 +81,19 @@ pub struct UserState {
     pub member_info: Option<MemberInfo>,
     pub latest_sales: Vec<Sale>,
     pub error: Option<String>,
+    pub updated_at: Option<DateTime<Local>>,
+    pub stale: bool,
+}
+
+#[derive(Clone)]
+pub struct CartState {
+    pub lines: Vec<CartLine>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CartLine {
+    pub product_id: String,
+    pub quantity: u32,
 }
 
 #[derive(Clone)]
@@ -62,7 +103,12 @@ pub struct ModalState {
     pub search: SearchModalState,
     pub error: ErrorModalState,
     pub parking: ParkingModalState,
+    pub parking_log: ParkingLogModalState,
     pub terminal_size: TerminalSizeModalState,
+    pub cart: CartModalState,
+    pub quick_buy: QuickBuyModalState,
+    pub history: HistoryModalState,
+    pub stats: StatsModalState,
 }
 
 #[derive(Clone)]
@@ -70,6 +116,7 @@ pub struct ErrorModalState {
     pub visible: bool,
     pub message: Option<String>,
     pub title: Option<String>,
+    pub success: bool,
 }
 
 #[derive(Clone)]
@@ -85,6 +132,8 @@ pub struct PurchaseModalState {
     pub error: Option<String>,
     pub success: bool,
     pub quantity: u32,
+    pub response: Option<PurchaseResponse>,
+    pub pending: bool,
 }
 
 #[derive(Clone)]
@@ -98,12 +147,60 @@ pub struct SearchModalState {
 #[derive(Clone)]
 pub struct ParkingModalState {
     pub visible: bool,
-    pub phone_input: String,
-    pub license_plate_input: String,
     pub current_field: usize,
+    pub phone_index: usize,
+    pub vehicle_index: usize,
+    pub editor: Option<ParkingEditor>,
+    pub profile_index: usize,
     pub error: Option<String>,
     pub success: bool,
+    pub permit: Option<ParkingPermit>,
     pub confirming: bool,
+    pub pending: bool,
+}
+
+#[derive(Clone)]
+pub enum ParkingEditor {
+    Phone {
+        index: Option<usize>,
+        number: String,
+    },
+    Vehicle {
+        index: Option<usize>,
+        field: usize,
+        plate: String,
+        country: String,
+        nickname: String,
+    },
+}
+
+#[derive(Clone)]
+pub struct ParkingLogModalState {
+    pub visible: bool,
+    pub selected_index: usize,
+}
+
+#[derive(Clone)]
+pub struct HistoryModalState {
+    pub visible: bool,
+    pub scroll: usize,
+    pub product: String,
+    pub from: String,
+    pub to: String,
+    pub editing: Option<HistoryField>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HistoryField {
+    Product,
+    From,
+    To,
+}
+
+#[derive(Clone)]
+pub struct StatsModalState {
+    pub visible: bool,
+    pub period: StatsPeriod,
 }
 
 #[derive(Clone)]
@@ -111,6 +208,27 @@ pub struct TerminalSizeModalState {
     pub visible: bool,
 }
 
+#[derive(Clone)]
+pub struct CartModalState {
+    pub visible: bool,
+    pub selected_index: usize,
+    pub error: Option<String>,
+    pub success: bool,
+    pub pending: bool,
+}
+
+#[derive(Clone)]
+pub struct QuickBuyModalState {
+    pub visible: bool,
+    pub input: String,
+    pub lines: Vec<(Product, u32)>,
+    pub parse_error: Option<String>,
+    pub confirming: bool,
+    pub error: Option<String>,
+    pub success: bool,
+    pub pending: bool,
+}
+
 impl AppState {
     pub fn new(config: Config) -> Self {
         let mut product_list_state = ListState::default();
@@ -121,10 +239,11 @@ impl AppState {
 
             ui: UiState {
                 input: String::new(),
-                input_mode: InputMode::Normal,
-                previous_input_mode: None,
+                base_mode: InputMode::Normal,
+                modal_stack: Vec::new(),
                 number_prefix: String::new(),
                 pending_g: false,
+                terminal_height: 0,
             },
 
             products: ProductsState {
@@ -133,6 +252,8 @@ impl AppState {
                 error: None,
                 named_products: HashMap::new(),
                 named_products_error: None,
+                updated_at: None,
+                stale: false,
             },
 
             user: UserState {
@@ -140,8 +261,12 @@ impl AppState {
                 member_info: None,
                 latest_sales: Vec::new(),
                 error: None,
+                updated_at: None,
+                stale: false,
             },
 
+            cart: CartState { lines: Vec::new() },
+
             modals: ModalState {
                 username: UsernameModalState {
                     visible: false,
@@ -153,6 +278,8 @@ impl AppState {
                     error: None,
                     success: false,
                     quantity: 1,
+                    response: None,
+                    pending: false,
                 },
                 search: SearchModalState {
                     visible: false,
@@ -164,19 +291,64 @@ impl AppState {
                     visible: false,
                     message: None,
                     title: None,
+                    success: false,
                 },
                 parking: ParkingModalState {
                     visible: false,
-                    phone_input: String::new(),
-                    license_plate_input: String::new(),
                     current_field: 0,
+                    phone_index: 0,
+                    vehicle_index: 0,
+                    editor: None,
+                    profile_index: 0,
                     error: None,
                     success: false,
+                    permit: None,
                     confirming: false,
+                    pending: false,
+                },
+                parking_log: ParkingLogModalState {
+                    visible: false,
+                    selected_index: 0,
                 },
                 terminal_size: TerminalSizeModalState { visible: false },
+                cart: CartModalState {
+                    visible: false,
+                    selected_index: 0,
+                    error: None,
+                    success: false,
+                    pending: false,
+                },
+                quick_buy: QuickBuyModalState {
+                    visible: false,
+                    input: String::new(),
+                    lines: Vec::new(),
+                    parse_error: None,
+                    confirming: false,
+                    error: None,
+                    success: false,
+                    pending: false,
+                },
+                history: HistoryModalState {
+                    visible: false,
+                    scroll: 0,
+                    product: String::new(),
+                    from: String::new(),
+                    to: String::new(),
+                    editing: None,
+                },
+                stats: StatsModalState {
+                    visible: false,
+                    period: StatsPeriod::Day,
+                },
             },
 
+            tasks: TaskState::default(),
+
+            parking_log: Vec::new(),
+            ledger: Vec::new(),
+            last_scheduled_slot: None,
+            last_refresh: None,
+
             should_quit: false,
         }
     }
@@ -192,14 +364,54 @@ impl AppState {
     pub fn get_sorted_products(&self) -> Vec<&Product> {
         let mut products_vec: Vec<&Product> = self.products.items.values().collect();
 
-        products_vec.sort_by(|a, b| match (a.id.parse::<i32>(), b.id.parse::<i32>()) {
-            (Ok(id_a), Ok(id_b)) => id_a.cmp(&id_b),
-            _ => a.id.cmp(&b.id),
+        products_vec.sort_by(|a, b| {
+            let a_favorite = self.config.is_favorite(&a.id);
+            let b_favorite = self.config.is_favorite(&b.id);
+
+            b_favorite.cmp(&a_favorite).then_with(|| a.cmp_by_id(b))
         });
 
         products_vec
     }
 
+    pub fn toggle_selected_favorite(&mut self) -> Option<bool> {
+        let selected = self.products.list_state.selected()?;
+        let product_id = self.get_sorted_products().get(selected)?.id.clone();
+
+        let is_favorite = self.config.toggle_favorite(&product_id);
+
+        let new_index = self
+            .get_sorted_products()
+            .iter()
+            .position(|product| product.id == product_id);
+        self.products.list_state.select(new_index);
+
+        Some(is_favorite)
+    }
+
+    pub fn select_next_favorite(&mut self) {
+        let favorite_indices: Vec<usize> = self
+            .get_sorted_products()
+            .iter()
+            .enumerate()
+            .filter(|(_, product)| self.config.is_favorite(&product.id))
+            .map(|(index, _)| index)
+            .collect();
+
+        if favorite_indices.is_empty() {
+            return;
+        }
+
+        let current = self.products.list_state.selected().unwrap_or(0);
+        let next = favorite_indices
+            .iter()
+            .copied()
+            .find(|&index| index > current)
+            .unwrap_or(favorite_indices[0]);
+
+        self.products.list_state.select(Some(next));
+    }
+
     pub fn get_movement_target_indices(&self) -> Vec<usize> {
         if self.ui.number_prefix.is_empty() {
             return Vec::new();
@@ -226,16 +438,65 @@ impl AppState {
         targets
     }
 
-    pub fn push_input_mode(&mut self, new_mode: InputMode) {
-        self.ui.previous_input_mode = Some(self.ui.input_mode);
-        self.ui.input_mode = new_mode;
+    pub fn take_count(&mut self) -> usize {
+        let count = self.ui.number_prefix.parse::<usize>().unwrap_or(1);
+        self.ui.number_prefix.clear();
+        count
     }
 
-    pub fn pop_input_mode(&mut self) {
-        if let Some(mode) = self.ui.previous_input_mode.take() {
-            self.ui.input_mode = mode;
+    pub fn move_products_down(&mut self, count: usize) {
+        let len = self.products.items.len();
+        if len == 0 {
+            return;
+        }
+
+        let current = self.products.list_state.selected().unwrap_or(0);
+
+        let target_down = current + count;
+        if target_down < len {
+            self.products.list_state.select(Some(target_down));
         } else {
-            self.ui.input_mode = InputMode::Normal;
+            let new_index = (current + 1).min(len - 1);
+            self.products.list_state.select(Some(new_index));
+        }
+    }
+
+    pub fn move_products_up(&mut self, count: usize) {
+        if self.products.items.is_empty() {
+            return;
+        }
+
+        let current = self.products.list_state.selected().unwrap_or(0);
+
+        if current >= count {
+            self.products.list_state.select(Some(current - count));
+        } else {
+            let new_index = current.saturating_sub(1);
+            self.products.list_state.select(Some(new_index));
+        }
+    }
+
+    pub fn go_to_top(&mut self) {
+        self.products.list_state.select(Some(0));
+        self.ui.number_prefix.clear();
+    }
+
+    pub fn go_to_bottom(&mut self) {
+        let len = self.products.items.len();
+        if len > 0 {
+            self.products.list_state.select(Some(len - 1));
+        }
+        self.ui.number_prefix.clear();
+    }
+
+    pub fn push_input_mode(&mut self, mode: InputMode) {
+        self.ui.modal_stack.retain(|open| *open != mode);
+        self.ui.modal_stack.push(mode);
+    }
+
+    pub fn pop_input_mode(&mut self, mode: InputMode) {
+        if let Some(index) = self.ui.modal_stack.iter().rposition(|open| *open == mode) {
+            self.ui.modal_stack.remove(index);
         }
     }
 
@@ -253,4 +514,27 @@ impl AppState {
         }
         Ok(())
     }
+
+    pub fn purchase_request(
+        &self,
+        items: Vec<BuyItem>,
+        total_cost: Money,
+    ) -> Result<PurchaseRequest, String> {
+        let (Some(member_id), Some(member_info)) = (self.user.member_id, &self.user.member_info)
+        else {
+            return Err("Unable to process purchase: missing required information".to_string());
+        };
+
+        if member_info.balance < total_cost {
+            return Err(format!(
+                "Insufficient balance. This purchase requires {total_cost}"
+            ));
+        }
+
+        Ok(PurchaseRequest {
+            member_id,
+            buystring: format_buystring(&member_info.username, &items),
+            items,
+        })
+    }
 }
@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use chrono::{Datelike, Duration, Months, NaiveDate, Timelike};
+
+use crate::models::sale::Sale;
+use crate::utils::formatters::sanitize_html;
+use crate::utils::money::Money;
+
+pub const TOP_PRODUCTS: usize = 8;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatsPeriod {
+    Day,
+    Week,
+    Month,
+}
+
+impl StatsPeriod {
+    pub fn next(self) -> Self {
+        match self {
+            StatsPeriod::Day => StatsPeriod::Week,
+            StatsPeriod::Week => StatsPeriod::Month,
+            StatsPeriod::Month => StatsPeriod::Day,
+        }
+    }
+
+    pub fn previous(self) -> Self {
+        match self {
+            StatsPeriod::Day => StatsPeriod::Month,
+            StatsPeriod::Week => StatsPeriod::Day,
+            StatsPeriod::Month => StatsPeriod::Week,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatsPeriod::Day => "day",
+            StatsPeriod::Week => "week",
+            StatsPeriod::Month => "month",
+        }
+    }
+
+    fn bucket_count(self) -> usize {
+        match self {
+            StatsPeriod::Day => 14,
+            StatsPeriod::Week | StatsPeriod::Month => 12,
+        }
+    }
+
+    fn bucket_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            StatsPeriod::Day => date,
+            StatsPeriod::Week => {
+                date - Duration::days(date.weekday().num_days_from_monday() as i64)
+            }
+            StatsPeriod::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    fn bucket_before(self, start: NaiveDate) -> NaiveDate {
+        match self {
+            StatsPeriod::Day => start - Duration::days(1),
+            StatsPeriod::Week => start - Duration::weeks(1),
+            StatsPeriod::Month => start - Months::new(1),
+        }
+    }
+
+    fn bucket_label(self, start: NaiveDate) -> String {
+        match self {
+            StatsPeriod::Day => start.format("%d/%m").to_string(),
+            StatsPeriod::Week => format!("w{}", start.iso_week().week()),
+            StatsPeriod::Month => start.format("%b").to_string(),
+        }
+    }
+}
+
+pub struct ProductStats {
+    pub name: String,
+    pub count: u32,
+    pub total: Money,
+}
+
+pub struct SpendingStats {
+    pub total: Money,
+    pub count: u32,
+    pub average: Money,
+    pub periods: Vec<(String, Money)>,
+    pub top_products: Vec<ProductStats>,
+    pub hourly: [u64; 24],
+}
+
+pub fn spending_stats(sales: &[Sale], period: StatsPeriod, today: NaiveDate) -> SpendingStats {
+    let timed: Vec<_> = sales
+        .iter()
+        .filter_map(|sale| Some((sale.local_time()?, sale)))
+        .collect();
+
+    let total: Money = timed.iter().map(|(_, sale)| sale.price).sum();
+    let count = timed.len() as u32;
+    let average = if count == 0 {
+        Money::new(0)
+    } else {
+        total / count
+    };
+
+    let mut starts = vec![period.bucket_start(today)];
+    while starts.len() < period.bucket_count() {
+        let previous = period.bucket_before(starts[starts.len() - 1]);
+        starts.push(previous);
+    }
+    starts.reverse();
+
+    let mut spent: HashMap<NaiveDate, Money> = HashMap::new();
+    let mut products: HashMap<String, ProductStats> = HashMap::new();
+    let mut hourly = [0; 24];
+
+    for (time, sale) in &timed {
+        let start = period.bucket_start(time.date_naive());
+        let amount = spent.entry(start).or_insert(Money::new(0));
+        *amount = *amount + sale.price;
+
+        let name = sanitize_html(&sale.product);
+        let product = products.entry(name.clone()).or_insert(ProductStats {
+            name,
+            count: 0,
+            total: Money::new(0),
+        });
+        product.count += 1;
+        product.total = product.total + sale.price;
+
+        hourly[time.hour() as usize] += 1;
+    }
+
+    let periods = starts
+        .into_iter()
+        .map(|start| {
+            let amount = spent.get(&start).copied().unwrap_or(Money::new(0));
+            (period.bucket_label(start), amount)
+        })
+        .collect();
+
+    let mut top_products: Vec<ProductStats> = products.into_values().collect();
+    top_products.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then_with(|| b.total.cmp(&a.total))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    top_products.truncate(TOP_PRODUCTS);
+
+    SpendingStats {
+        total,
+        count,
+        average,
+        periods,
+        top_products,
+        hourly,
+    }
+}
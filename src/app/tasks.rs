@@ -0,0 +1,439 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::app::buystring::BuyItem;
+use crate::app::effects::Effect;
+use crate::app::modals::error::ErrorModalActions;
+use crate::app::modals::parking::ParkingModalActions;
+use crate::app::schedule::ScheduleOutcome;
+use crate::app::state::AppState;
+use crate::error::{AppError, Result};
+use crate::models::member::MemberInfo;
+use crate::models::parking::{ParkingPermitRequest, ParkingRegistration};
+use crate::models::permit::ParkingPermit;
+use crate::models::product::Product;
+use crate::models::purchase::PurchaseResponse;
+use crate::models::sale::Sale;
+use crate::utils::formatters::format_error_message;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TaskKind {
+    Products,
+    User,
+    Purchase,
+    Parking,
+    ScheduledParking,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PurchaseTarget {
+    Single,
+    Cart,
+    QuickBuy,
+}
+
+#[derive(Debug, Clone)]
+pub struct PurchaseRequest {
+    pub member_id: i32,
+    pub buystring: String,
+    pub items: Vec<BuyItem>,
+}
+
+pub struct UserData {
+    pub member_id: i32,
+    pub member_info: Result<MemberInfo>,
+    pub latest_sales: Result<Vec<Sale>>,
+}
+
+pub enum TaskResult {
+    ProductsLoaded {
+        products: Result<HashMap<String, Product>>,
+        named_products: Result<HashMap<String, i32>>,
+    },
+    UserLoaded {
+        username: String,
+        login: bool,
+        result: Result<Option<UserData>>,
+    },
+    PurchaseCompleted {
+        target: PurchaseTarget,
+        items: Vec<BuyItem>,
+        result: Result<PurchaseResponse>,
+    },
+    ParkingRegistered {
+        request: ParkingPermitRequest,
+        result: Result<Box<ParkingPermit>>,
+    },
+    ScheduledParking {
+        result: Result<Box<ScheduleOutcome>>,
+        hook: Result<()>,
+    },
+}
+
+impl TaskResult {
+    pub fn kind(&self) -> TaskKind {
+        match self {
+            TaskResult::ProductsLoaded { .. } => TaskKind::Products,
+            TaskResult::UserLoaded { .. } => TaskKind::User,
+            TaskResult::PurchaseCompleted { .. } => TaskKind::Purchase,
+            TaskResult::ParkingRegistered { .. } => TaskKind::Parking,
+            TaskResult::ScheduledParking { .. } => TaskKind::ScheduledParking,
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct TaskState {
+    pub running: HashSet<TaskKind>,
+    pub spinner_frame: usize,
+}
+
+impl TaskState {
+    pub fn is_running(&self, kind: TaskKind) -> bool {
+        self.running.contains(&kind)
+    }
+}
+
+impl AppState {
+    pub fn apply_task_result(&mut self, result: TaskResult) -> Vec<Effect> {
+        match result {
+            TaskResult::ProductsLoaded {
+                products,
+                named_products,
+            } => {
+                let loaded = products.is_ok();
+                let selected = self.selected_product_id();
+
+                match products {
+                    Ok(products) => {
+                        self.products.items = products;
+                        self.products.error = None;
+                        self.products.updated_at = Some(chrono::Local::now());
+                        self.products.stale = false;
+                    }
+                    Err(_) if !self.products.items.is_empty() => {
+                        self.products.stale = true;
+                    }
+                    Err(e) => {
+                        self.products.error = Some(e.to_string());
+                    }
+                }
+
+                match named_products {
+                    Ok(named_products) => {
+                        self.products.named_products = named_products;
+                        self.products.named_products_error = None;
+                    }
+                    Err(e) => {
+                        self.products.named_products_error = Some(e.to_string());
+                    }
+                }
+
+                self.reselect_product(selected);
+
+                if loaded {
+                    vec![Effect::SaveCache]
+                } else {
+                    Vec::new()
+                }
+            }
+            TaskResult::UserLoaded {
+                username,
+                login,
+                result,
+            } => {
+                if self.config.username() != Some(&username) {
+                    return Vec::new();
+                }
+
+                self.apply_user_data(&username, login, result)
+            }
+            TaskResult::PurchaseCompleted {
+                target,
+                items,
+                result,
+            } => self.apply_purchase_result(target, &items, result),
+            TaskResult::ParkingRegistered { request, result } => {
+                self.modals.parking.pending = false;
+
+                match result.map(|permit| *permit) {
+                    Ok(permit) => {
+                        self.modals.parking.success = true;
+                        self.modals.parking.error = None;
+                        self.modals.parking.permit = Some(permit.clone());
+
+                        let registration = ParkingRegistration::new(
+                            &self.parking_profile().name,
+                            &request,
+                            permit,
+                        );
+                        self.parking_log.push(registration.clone());
+
+                        vec![Effect::LogParking(registration)]
+                    }
+                    Err(AppError::DryRun(_)) => {
+                        self.modals.parking.success = false;
+                        self.modals.parking.error =
+                            Some("Dry run, parking was not registered".to_string());
+
+                        Vec::new()
+                    }
+                    Err(AppError::Timeout { .. }) => {
+                        self.modals.parking.success = false;
+                        self.modals.parking.error = Some(
+                            "Timed out, it may still be registered. Check your SMS".to_string(),
+                        );
+
+                        Vec::new()
+                    }
+                    Err(e) => {
+                        self.modals.parking.success = false;
+                        self.modals.parking.error =
+                            Some(format!("Failed to register parking: {e}"));
+
+                        Vec::new()
+                    }
+                }
+            }
+            TaskResult::ScheduledParking { result, hook } => {
+                self.apply_scheduled_parking(result, hook);
+                Vec::new()
+            }
+        }
+    }
+
+    fn apply_scheduled_parking(&mut self, result: Result<Box<ScheduleOutcome>>, hook: Result<()>) {
+        let (mut message, success) = match result.map(|outcome| *outcome) {
+            Ok(ScheduleOutcome::AlreadyRegistered(registration)) => {
+                if !self.parking_log.contains(&registration) {
+                    self.parking_log.push(registration);
+                }
+                return;
+            }
+            Ok(outcome) => {
+                self.parking_log.push(outcome.registration().clone());
+                (outcome.message(), true)
+            }
+            Err(AppError::DryRun(_)) => (
+                "Dry run, scheduled parking was not registered".to_string(),
+                false,
+            ),
+            Err(e) => (format!("Scheduled parking failed: {e}"), false),
+        };
+
+        if let Err(e) = hook {
+            message.push_str(&format!(" The parking hook failed: {e}"));
+        }
+
+        let message = format_error_message(&message, 50, 10);
+        if success {
+            self.show_success_modal(&message, Some("Scheduled Parking"));
+        } else {
+            self.show_error_modal(&message, Some("Scheduled Parking"));
+        }
+    }
+
+    fn apply_user_data(
+        &mut self,
+        username: &str,
+        login: bool,
+        result: Result<Option<UserData>>,
+    ) -> Vec<Effect> {
+        if let (Err(e), Some(_)) = (&result, &self.user.member_info) {
+            if !is_client_error(e) {
+                self.user.stale = true;
+                return Vec::new();
+            }
+        }
+
+        self.user.error = None;
+        self.user.member_info = None;
+        self.user.member_id = None;
+        self.user.latest_sales = Vec::new();
+        self.user.stale = false;
+
+        let mut effects = Vec::new();
+
+        match result {
+            Ok(Some(data)) => {
+                self.user.member_id = Some(data.member_id);
+
+                match data.member_info {
+                    Ok(info) => {
+                        self.user.member_info = Some(info);
+                        self.user.updated_at = Some(chrono::Local::now());
+                        self.user.stale = false;
+                    }
+                    Err(e) => {
+                        self.user.error = Some(format!("Failed to fetch member info: {e}"));
+                    }
+                }
+
+                match data.latest_sales {
+                    Ok(sales) => {
+                        self.user.latest_sales = sales;
+                        effects.extend(self.record_fetched_sales());
+                    }
+                    Err(e) => {
+                        if self.user.error.is_none() {
+                            self.user.error = Some(format!("Failed to fetch sales: {e}"));
+                        }
+                    }
+                }
+
+                if self.user.member_info.is_some() {
+                    effects.push(Effect::SaveCache);
+                }
+            }
+            Ok(None) => {
+                self.user.error = Some(format!("Username '{username}' does not exist"));
+            }
+            Err(e) if !login && !is_client_error(&e) => {
+                self.user.error = Some(format!("Failed to load user data: {e}"));
+            }
+            Err(e) => {
+                self.handle_invalid_username(&format!("Failed to load user data: {e}"));
+
+                let error_msg = format_error_message(
+                    &format!("Check that the username exists and try again!\n{e}"),
+                    50,
+                    10,
+                );
+                self.show_error_modal(&error_msg, Some("Username Error"));
+            }
+        }
+
+        effects
+    }
+
+    fn apply_purchase_result(
+        &mut self,
+        target: PurchaseTarget,
+        items: &[BuyItem],
+        result: Result<PurchaseResponse>,
+    ) -> Vec<Effect> {
+        let response = match result {
+            Ok(response) => response,
+            Err(AppError::DryRun(_)) => {
+                self.fail_purchase(target, "Dry run, nothing was bought".to_string());
+                return Vec::new();
+            }
+            Err(AppError::Timeout { .. }) => {
+                self.fail_purchase(
+                    target,
+                    "Timed out, the purchase may still go through".to_string(),
+                );
+                return self.load_user_effect().into_iter().collect();
+            }
+            Err(e) => {
+                self.fail_purchase(target, format!("Purchase failed: {e}"));
+                return Vec::new();
+            }
+        };
+
+        let effects = self.apply_purchase_response(items, &response);
+
+        match target {
+            PurchaseTarget::Single => {
+                self.modals.purchase.pending = false;
+                self.modals.purchase.success = true;
+                self.modals.purchase.response = Some(response);
+            }
+            PurchaseTarget::Cart => {
+                self.modals.cart.pending = false;
+                self.modals.cart.success = true;
+                self.cart.lines.clear();
+                self.modals.cart.selected_index = 0;
+            }
+            PurchaseTarget::QuickBuy => {
+                self.modals.quick_buy.pending = false;
+                self.modals.quick_buy.success = true;
+            }
+        }
+
+        effects
+    }
+
+    fn apply_purchase_response(
+        &mut self,
+        items: &[BuyItem],
+        response: &PurchaseResponse,
+    ) -> Vec<Effect> {
+        let (Some(balance), Some(member_info)) =
+            (response.member_balance(), self.user.member_info.as_mut())
+        else {
+            return self.load_user_effect().into_iter().collect();
+        };
+
+        member_info.balance = balance;
+        self.user.updated_at = Some(chrono::Local::now());
+        self.user.stale = false;
+
+        let timestamp = chrono::Local::now().to_rfc3339();
+        let mut sales = Vec::new();
+        for item in items {
+            if let Some(product) = self.products.items.get(&item.product_id) {
+                for _ in 0..item.quantity {
+                    let sale = Sale {
+                        timestamp: timestamp.clone(),
+                        product: product.name.clone(),
+                        price: product.price,
+                    };
+                    self.user.latest_sales.insert(0, sale.clone());
+                    sales.push(sale);
+                }
+            }
+        }
+
+        let mut effects = vec![Effect::SaveCache];
+        effects.extend(self.record_local_sales(sales));
+        effects
+    }
+
+    pub fn load_user_effect(&self) -> Option<Effect> {
+        self.config.username().map(|username| Effect::LoadUser {
+            username: username.clone(),
+            login: false,
+        })
+    }
+
+    pub fn login_effect(&self) -> Option<Effect> {
+        self.config.username().map(|username| Effect::LoadUser {
+            username: username.clone(),
+            login: true,
+        })
+    }
+
+    pub fn cancel_pending_purchase(&mut self, target: PurchaseTarget) {
+        self.fail_purchase(
+            target,
+            "Cancelled, the purchase may still go through".to_string(),
+        );
+    }
+
+    fn fail_purchase(&mut self, target: PurchaseTarget, message: String) {
+        match target {
+            PurchaseTarget::Single => {
+                self.modals.purchase.pending = false;
+                self.modals.purchase.error = Some(message);
+            }
+            PurchaseTarget::Cart => {
+                self.modals.cart.pending = false;
+                self.modals.cart.error = Some(message);
+            }
+            PurchaseTarget::QuickBuy => {
+                self.modals.quick_buy.pending = false;
+                self.modals.quick_buy.error = Some(message);
+            }
+        }
+    }
+
+    pub fn cancel_pending_parking(&mut self) {
+        self.modals.parking.pending = false;
+        self.modals.parking.error =
+            Some("Cancelled, it may still be registered. Check your SMS".to_string());
+    }
+}
+
+fn is_client_error(e: &AppError) -> bool {
+    matches!(e, AppError::HttpStatus { status, .. } if status.is_client_error())
+}
@@ -0,0 +1,114 @@
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+
+use crate::api::demo::DEMO_USERNAME;
+use crate::config::settings::{Config, ConfigOverrides};
+use crate::config::store::{load_or_create_config, load_or_create_config_at};
+use crate::error::Result;
+
+#[derive(Parser, Debug)]
+#[command(name = "stui", version, about = "A TUI for Stregsystemet")]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Print machine-readable JSON instead of plain text
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Stregsystemet API base URL [env: STUI_API_URL]
+    #[arg(long, global = true, value_name = "URL")]
+    pub api_url: Option<String>,
+
+    /// Room to buy products in [env: STUI_ROOM_ID]
+    #[arg(long, global = true, value_name = "ID")]
+    pub room: Option<u32>,
+
+    /// Path to the configuration file [default: ~/.config/.stregsystemet.toml]
+    #[arg(long, global = true, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Use bundled demo data instead of Stregsystemet; nothing is bought or saved
+    #[arg(long, global = true)]
+    pub demo: bool,
+
+    /// Show the requests that purchases and parking would send, without sending them
+    #[arg(long, global = true)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    Text,
+    Json,
+}
+
+impl Cli {
+    pub fn output_format(&self) -> OutputFormat {
+        if self.json {
+            OutputFormat::Json
+        } else {
+            OutputFormat::Text
+        }
+    }
+
+    pub fn load_config(&self) -> Result<Config> {
+        let overrides = ConfigOverrides {
+            username: None,
+            room_id: self.room,
+            api_url: self.api_url.clone(),
+            dry_run: self.dry_run,
+        }
+        .or_env()?;
+
+        if self.demo {
+            return Ok(Config {
+                overrides: ConfigOverrides {
+                    username: Some(DEMO_USERNAME.to_string()),
+                    ..overrides
+                },
+                demo: true,
+                ..Config::default()
+            });
+        }
+
+        let mut config = match self.config {
+            Some(ref path) => load_or_create_config_at(path)?,
+            None => load_or_create_config()?,
+        };
+        config.overrides = overrides;
+
+        Ok(config)
+    }
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Buy products, e.g. `stui buy 14:2 32`
+    Buy {
+        #[arg(required = true, value_name = "ID[:QTY]")]
+        items: Vec<String>,
+    },
+
+    /// Show your current balance
+    Balance,
+
+    /// Show your latest purchases
+    History,
+
+    /// List all active products
+    Products,
+
+    /// Search products by name, ID or keyword
+    Search {
+        #[arg(required = true)]
+        query: Vec<String>,
+    },
+
+    /// Register parking if the configured parking schedule is due
+    Park {
+        /// Keep running and register parking every time the schedule is due
+        #[arg(long)]
+        daemon: bool,
+    },
+}
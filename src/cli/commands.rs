@@ -0,0 +1,298 @@
+use std::process::ExitCode;
+use std::time::Duration;
+
+use chrono::Local;
+
+use crate::api::backend::{create_backend, StregApi};
+use crate::app::buystring::{
+    format_buystring, parse_buy_items, resolve_buy_items, resolved_items, resolved_total,
+};
+use crate::app::schedule::{due_parking, register_scheduled_parking, run_parking_hook};
+use crate::app::search::search_products;
+use crate::cli::args::{Command, OutputFormat};
+use crate::cli::output::{self, CommandOutput, PurchaseSummary};
+use crate::config::parking_log::load_parking_log;
+use crate::config::settings::Config;
+use crate::error::{AppError, Result};
+use crate::models::member::MemberInfo;
+use crate::models::product::Product;
+
+const PARK_DAEMON_INTERVAL: Duration = Duration::from_secs(30);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CliStatus {
+    Failure = 1,
+    InvalidInput = 2,
+    NotConfigured = 3,
+    UserNotFound = 4,
+    InsufficientBalance = 5,
+    Unavailable = 6,
+}
+
+#[derive(Debug)]
+pub struct CliFailure {
+    pub status: CliStatus,
+    pub message: String,
+}
+
+impl CliFailure {
+    fn new(status: CliStatus, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<AppError> for CliFailure {
+    fn from(err: AppError) -> Self {
+        let status = match err {
+            AppError::Io(_) | AppError::DryRun(_) => CliStatus::Failure,
+            AppError::Network(_)
+            | AppError::Timeout { .. }
+            | AppError::HttpStatus { .. }
+            | AppError::Decode { .. }
+            | AppError::Api(_) => CliStatus::Unavailable,
+            AppError::Config(_) => CliStatus::NotConfigured,
+            AppError::Input(_) => CliStatus::InvalidInput,
+        };
+
+        Self::new(status, err.to_string())
+    }
+}
+
+type CliResult<T> = std::result::Result<T, CliFailure>;
+
+pub async fn run(command: Command, format: OutputFormat, config: Result<Config>) -> ExitCode {
+    if let (Command::Park { daemon: true }, Ok(config)) = (&command, &config) {
+        return park_daemon(config, format).await;
+    }
+
+    let result = match config {
+        Ok(config) => execute(command, &config).await,
+        Err(e) => Err(CliFailure::from(e)),
+    };
+
+    match result {
+        Ok(output) => {
+            output::print(&output, format);
+            ExitCode::SUCCESS
+        }
+        Err(failure) => {
+            output::print_failure(&failure, format);
+            ExitCode::from(failure.status as u8)
+        }
+    }
+}
+
+async fn execute(command: Command, config: &Config) -> CliResult<CommandOutput> {
+    let api_client = create_backend(config)?;
+    let api_client = api_client.as_ref();
+
+    match command {
+        Command::Buy { items } => buy(api_client, config, &items.join(" ")).await,
+        Command::Balance => balance(api_client, config).await,
+        Command::History => history(api_client, config).await,
+        Command::Products => products(api_client).await,
+        Command::Search { query } => search(api_client, &query.join(" ")).await,
+        Command::Park { .. } => park(api_client, config).await,
+    }
+}
+
+async fn buy(api_client: &dyn StregApi, config: &Config, input: &str) -> CliResult<CommandOutput> {
+    let username = require_username(config)?;
+    let items = parse_buy_items(input)?;
+
+    let (products, named_products) = tokio::join!(
+        api_client.fetch_products(),
+        api_client.fetch_named_products()
+    );
+    let products = products?;
+    let named_products = named_products.unwrap_or_default();
+
+    let lines = resolve_buy_items(&items, &products, &named_products)?;
+    let total = resolved_total(&lines)?;
+
+    let (member_id, member_info) = lookup_member(api_client, username).await?;
+
+    if member_info.balance < total {
+        return Err(CliFailure::new(
+            CliStatus::InsufficientBalance,
+            format!(
+                "Insufficient balance. This purchase requires {total}, you have {}",
+                member_info.balance
+            ),
+        ));
+    }
+
+    let buystring = format_buystring(&member_info.username, &resolved_items(&lines));
+    let response = match api_client.make_purchase(member_id, &buystring).await {
+        Err(AppError::DryRun(request)) => return Ok(CommandOutput::DryRun(*request)),
+        result => result?,
+    };
+    let total = response.cost().unwrap_or(total);
+
+    let balance = match response.member_balance() {
+        Some(balance) => balance,
+        None => match api_client.fetch_member_info(member_id).await {
+            Ok(info) => info.balance,
+            Err(_) => member_info.balance - total,
+        },
+    };
+
+    Ok(CommandOutput::Purchase(PurchaseSummary {
+        buystring,
+        lines,
+        total,
+        balance,
+    }))
+}
+
+async fn balance(api_client: &dyn StregApi, config: &Config) -> CliResult<CommandOutput> {
+    let username = require_username(config)?;
+    let (_, member_info) = lookup_member(api_client, username).await?;
+
+    Ok(CommandOutput::Member(member_info))
+}
+
+async fn history(api_client: &dyn StregApi, config: &Config) -> CliResult<CommandOutput> {
+    let username = require_username(config)?;
+    let (member_id, _) = lookup_member(api_client, username).await?;
+    let sales = api_client.fetch_latest_sales(member_id).await?;
+
+    Ok(CommandOutput::Sales(sales))
+}
+
+async fn products(api_client: &dyn StregApi) -> CliResult<CommandOutput> {
+    let products = api_client.fetch_products().await?;
+
+    let mut sorted: Vec<Product> = products.into_values().collect();
+    sorted.sort_by(|a, b| a.cmp_by_id(b));
+
+    Ok(CommandOutput::Products(sorted))
+}
+
+async fn search(api_client: &dyn StregApi, query: &str) -> CliResult<CommandOutput> {
+    let (products, named_products) = tokio::join!(
+        api_client.fetch_products(),
+        api_client.fetch_named_products()
+    );
+    let products = products?;
+    let named_products = named_products.unwrap_or_default();
+
+    let results = search_products(&products, &named_products, query);
+
+    if results.is_empty() {
+        return Err(CliFailure::new(
+            CliStatus::InvalidInput,
+            format!("No products matching '{query}'"),
+        ));
+    }
+
+    Ok(CommandOutput::Products(results))
+}
+
+async fn park(api_client: &dyn StregApi, config: &Config) -> CliResult<CommandOutput> {
+    require_parking_schedule(config)?;
+
+    let log = load_parking_log(config)?;
+    let Some(scheduled) = due_parking(config, &log, Local::now()) else {
+        return Ok(CommandOutput::Parking(None));
+    };
+
+    let result = register_scheduled_parking(api_client, &scheduled).await;
+    if let Err(e) = run_parking_hook(&scheduled, &result).await {
+        eprintln!("Warning: {e}");
+    }
+
+    match result {
+        Err(AppError::DryRun(request)) => Ok(CommandOutput::DryRun(*request)),
+        result => Ok(CommandOutput::Parking(Some(result?))),
+    }
+}
+
+async fn park_daemon(config: &Config, format: OutputFormat) -> ExitCode {
+    let api_client = match require_parking_schedule(config)
+        .and_then(|_| create_backend(config).map_err(CliFailure::from))
+    {
+        Ok(api_client) => api_client,
+        Err(failure) => {
+            output::print_failure(&failure, format);
+            return ExitCode::from(failure.status as u8);
+        }
+    };
+
+    let mut last_slot = None;
+
+    loop {
+        let due = load_parking_log(config)
+            .map(|log| due_parking(config, &log, Local::now()))
+            .map_err(CliFailure::from);
+
+        match due {
+            Ok(Some(scheduled)) if last_slot != Some(scheduled.slot) => {
+                last_slot = Some(scheduled.slot);
+
+                let result = register_scheduled_parking(api_client.as_ref(), &scheduled).await;
+                let hook = run_parking_hook(&scheduled, &result).await;
+
+                match result {
+                    Ok(outcome) => output::print(&CommandOutput::Parking(Some(outcome)), format),
+                    Err(AppError::DryRun(request)) => {
+                        output::print(&CommandOutput::DryRun(*request), format)
+                    }
+                    Err(e) => output::print_failure(&e.into(), format),
+                }
+
+                if let Err(e) = hook {
+                    output::print_failure(&e.into(), format);
+                }
+            }
+            Ok(_) => {}
+            Err(failure) => output::print_failure(&failure, format),
+        }
+
+        tokio::time::sleep(PARK_DAEMON_INTERVAL).await;
+    }
+}
+
+fn require_parking_schedule(config: &Config) -> CliResult<()> {
+    if config.parking_schedule.is_none() {
+        return Err(CliFailure::new(
+            CliStatus::NotConfigured,
+            "No parking schedule configured. Add a [parking_schedule] section to the config file",
+        ));
+    }
+    Ok(())
+}
+
+fn require_username(config: &Config) -> CliResult<&str> {
+    config.username().map(String::as_str).ok_or_else(|| {
+        CliFailure::new(
+            CliStatus::NotConfigured,
+            "No username configured. Run `stui` to log in first",
+        )
+    })
+}
+
+async fn lookup_member(api_client: &dyn StregApi, username: &str) -> CliResult<(i32, MemberInfo)> {
+    let member_id = match api_client.fetch_member_id(username).await {
+        Ok(Some(member_id)) => member_id,
+        Ok(None) => return Err(user_not_found(username)),
+        Err(AppError::HttpStatus { status, .. }) if status.is_client_error() => {
+            return Err(user_not_found(username))
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    let member_info = api_client.fetch_member_info(member_id).await?;
+
+    Ok((member_id, member_info))
+}
+
+fn user_not_found(username: &str) -> CliFailure {
+    CliFailure::new(
+        CliStatus::UserNotFound,
+        format!("Username '{username}' does not exist"),
+    )
+}
@@ -0,0 +1,190 @@
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::api::dry_run::DryRunRequest;
+use crate::app::schedule::ScheduleOutcome;
+use crate::cli::output::PurchaseSummary;
+use crate::models::member::MemberInfo;
+use crate::models::product::Product;
+use crate::models::sale::Sale;
+use crate::utils::money::Money;
+
+#[derive(Serialize)]
+pub struct MoneyJson {
+    pub amount: i32,
+    pub kroner: i32,
+    pub ore: i32,
+    pub formatted: String,
+}
+
+impl From<Money> for MoneyJson {
+    fn from(money: Money) -> Self {
+        Self {
+            amount: money.as_cents(),
+            kroner: money.kroner(),
+            ore: money.ore(),
+            formatted: money.to_string(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ProductJson {
+    pub id: String,
+    pub name: String,
+    pub price: MoneyJson,
+}
+
+impl From<&Product> for ProductJson {
+    fn from(product: &Product) -> Self {
+        Self {
+            id: product.id.clone(),
+            name: product.name.clone(),
+            price: product.price.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct MemberJson {
+    pub username: String,
+    pub name: String,
+    pub balance: MoneyJson,
+}
+
+impl From<&MemberInfo> for MemberJson {
+    fn from(member_info: &MemberInfo) -> Self {
+        Self {
+            username: member_info.username.clone(),
+            name: member_info.name.clone(),
+            balance: member_info.balance.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct SaleJson {
+    pub timestamp: String,
+    pub product: String,
+    pub price: MoneyJson,
+}
+
+impl From<&Sale> for SaleJson {
+    fn from(sale: &Sale) -> Self {
+        Self {
+            timestamp: sale.iso_timestamp(),
+            product: sale.product.clone(),
+            price: sale.price.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PurchaseLineJson {
+    pub product: ProductJson,
+    pub quantity: u32,
+    pub total: MoneyJson,
+}
+
+#[derive(Serialize)]
+pub struct PurchaseJson {
+    pub buystring: String,
+    pub items: Vec<PurchaseLineJson>,
+    pub total: MoneyJson,
+    pub balance: MoneyJson,
+}
+
+impl From<&PurchaseSummary> for PurchaseJson {
+    fn from(summary: &PurchaseSummary) -> Self {
+        Self {
+            buystring: summary.buystring.clone(),
+            items: summary
+                .lines
+                .iter()
+                .map(|(product, quantity)| PurchaseLineJson {
+                    product: product.into(),
+                    quantity: *quantity,
+                    total: (product.price * *quantity).into(),
+                })
+                .collect(),
+            total: summary.total.into(),
+            balance: summary.balance.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ParkingJson {
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registration: Option<ParkingRegistrationJson>,
+}
+
+#[derive(Serialize)]
+pub struct ParkingRegistrationJson {
+    pub plate: String,
+    pub country: String,
+    pub profile: String,
+    pub zone_key: String,
+    pub registered_at: String,
+    pub expires_at: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warning: Option<&'static str>,
+}
+
+impl From<Option<&ScheduleOutcome>> for ParkingJson {
+    fn from(outcome: Option<&ScheduleOutcome>) -> Self {
+        let status = match outcome {
+            Some(ScheduleOutcome::Registered(_)) => "registered",
+            Some(ScheduleOutcome::AlreadyRegistered(_)) => "already_registered",
+            None => "not_due",
+        };
+
+        Self {
+            status,
+            registration: outcome.map(|outcome| {
+                let registration = outcome.registration();
+
+                ParkingRegistrationJson {
+                    plate: registration.plate.clone(),
+                    country: registration.country.clone(),
+                    profile: registration.profile.clone(),
+                    zone_key: registration.zone_key.clone(),
+                    registered_at: registration.registered_at.to_rfc3339(),
+                    expires_at: registration.expires_at().to_rfc3339(),
+                    warning: registration.permit.warning(),
+                }
+            }),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct DryRunJson {
+    pub dry_run: bool,
+    pub method: &'static str,
+    pub url: String,
+    pub body: Value,
+}
+
+impl From<&DryRunRequest> for DryRunJson {
+    fn from(request: &DryRunRequest) -> Self {
+        Self {
+            dry_run: true,
+            method: "POST",
+            url: request.url.clone(),
+            body: request.body.clone(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct ErrorJson {
+    pub error: ErrorDetailJson,
+}
+
+#[derive(Serialize)]
+pub struct ErrorDetailJson {
+    pub code: u8,
+    pub message: String,
+}
@@ -0,0 +1,147 @@
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+use crate::api::dry_run::DryRunRequest;
+use crate::app::schedule::ScheduleOutcome;
+use crate::cli::args::OutputFormat;
+use crate::cli::commands::CliFailure;
+use crate::cli::json::{
+    DryRunJson, ErrorDetailJson, ErrorJson, MemberJson, ParkingJson, ProductJson, PurchaseJson,
+    SaleJson,
+};
+use crate::models::member::MemberInfo;
+use crate::models::product::Product;
+use crate::models::sale::Sale;
+use crate::utils::money::Money;
+
+pub enum CommandOutput {
+    Products(Vec<Product>),
+    Member(MemberInfo),
+    Sales(Vec<Sale>),
+    Purchase(PurchaseSummary),
+    Parking(Option<ScheduleOutcome>),
+    DryRun(DryRunRequest),
+}
+
+pub struct PurchaseSummary {
+    pub buystring: String,
+    pub lines: Vec<(Product, u32)>,
+    pub total: Money,
+    pub balance: Money,
+}
+
+pub fn print(output: &CommandOutput, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => write_stdout(&format_text(output)),
+        OutputFormat::Json => print_json(output),
+    }
+}
+
+pub fn print_failure(failure: &CliFailure, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => eprintln!("Error: {}", failure.message),
+        OutputFormat::Json => emit_json(&ErrorJson {
+            error: ErrorDetailJson {
+                code: failure.status as u8,
+                message: failure.message.clone(),
+            },
+        }),
+    }
+}
+
+fn format_text(output: &CommandOutput) -> String {
+    let mut out = String::new();
+
+    match output {
+        CommandOutput::Products(products) => {
+            let id_width = products.iter().map(|p| p.id.len()).max().unwrap_or(0);
+
+            for product in products {
+                let _ = writeln!(
+                    out,
+                    "{:>id_width$}  {:>12}  {}",
+                    product.id,
+                    product.price.to_string(),
+                    product.name
+                );
+            }
+        }
+        CommandOutput::Member(member_info) => {
+            let _ = writeln!(
+                out,
+                "{} ({}): {}",
+                member_info.name, member_info.username, member_info.balance
+            );
+        }
+        CommandOutput::Sales(sales) => {
+            if sales.is_empty() {
+                let _ = writeln!(out, "No recent purchases");
+            }
+
+            for sale in sales {
+                let _ = writeln!(
+                    out,
+                    "{}  {:>12}  {}",
+                    sale.formatted_timestamp(),
+                    sale.price.to_string(),
+                    sale.product
+                );
+            }
+        }
+        CommandOutput::Purchase(summary) => {
+            for (product, quantity) in &summary.lines {
+                let _ = writeln!(
+                    out,
+                    "Bought {} × {} ({})",
+                    quantity,
+                    product.name,
+                    product.price * *quantity
+                );
+            }
+            let _ = writeln!(out, "Total: {}", summary.total);
+            let _ = writeln!(out, "New balance: {}", summary.balance);
+        }
+        CommandOutput::Parking(outcome) => {
+            let _ = match outcome {
+                Some(outcome) => writeln!(out, "{}", outcome.message()),
+                None => writeln!(out, "No scheduled parking due"),
+            };
+        }
+        CommandOutput::DryRun(request) => {
+            let body = serde_json::to_string_pretty(&request.body).unwrap_or_default();
+            let _ = writeln!(out, "Dry run, nothing was sent");
+            let _ = writeln!(out, "POST {}", request.url);
+            let _ = writeln!(out, "{body}");
+        }
+    }
+
+    out
+}
+
+fn print_json(output: &CommandOutput) {
+    match output {
+        CommandOutput::Products(products) => {
+            emit_json(&products.iter().map(ProductJson::from).collect::<Vec<_>>())
+        }
+        CommandOutput::Member(member_info) => emit_json(&MemberJson::from(member_info)),
+        CommandOutput::Sales(sales) => {
+            emit_json(&sales.iter().map(SaleJson::from).collect::<Vec<_>>())
+        }
+        CommandOutput::Purchase(summary) => emit_json(&PurchaseJson::from(summary)),
+        CommandOutput::Parking(outcome) => emit_json(&ParkingJson::from(outcome.as_ref())),
+        CommandOutput::DryRun(request) => emit_json(&DryRunJson::from(request)),
+    }
+}
+
+fn emit_json<T: Serialize>(value: &T) {
+    match serde_json::to_string_pretty(value) {
+        Ok(json) => write_stdout(&format!("{json}\n")),
+        Err(e) => eprintln!("Error: Failed to serialize output: {e}"),
+    }
+}
+
+fn write_stdout(text: &str) {
+    // Ignore write errors so piping into `head` and friends doesn't panic.
+    let _ = io::stdout().lock().write_all(text.as_bytes());
+}
@@ -0,0 +1,96 @@
+use chrono::{DateTime, Local};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io::ErrorKind;
+
+use crate::config::settings::Config;
+use crate::error::Result;
+use crate::models::member::MemberInfo;
+use crate::models::product::Product;
+use crate::models::sale::Sale;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct DataCache {
+    pub api_url: String,
+    pub room_id: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub products: Option<CachedProducts>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub member: Option<CachedMember>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CachedProducts {
+    pub fetched_at: DateTime<Local>,
+    pub items: HashMap<String, Product>,
+
+    #[serde(default)]
+    pub named_products: HashMap<String, i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CachedMember {
+    pub fetched_at: DateTime<Local>,
+    pub username: String,
+    pub member_id: i32,
+    pub info: MemberInfo,
+
+    #[serde(default)]
+    pub latest_sales: Vec<Sale>,
+}
+
+impl DataCache {
+    pub fn new(config: &Config) -> Self {
+        Self {
+            api_url: config.api_url(),
+            room_id: config.room_id(),
+            ..Self::default()
+        }
+    }
+}
+
+pub fn load_cache(config: &Config) -> Result<Option<DataCache>> {
+    if config.demo {
+        return Ok(None);
+    }
+
+    let content = match fs::read_to_string(Config::cache_path()?) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+
+    let Ok(mut cache) = serde_json::from_str::<DataCache>(&content) else {
+        return Ok(None);
+    };
+
+    if cache.api_url != config.api_url() || cache.room_id != config.room_id() {
+        return Ok(None);
+    }
+
+    if let Some(ref mut products) = cache.products {
+        for (product_id, product) in products.items.iter_mut() {
+            product.id = product_id.clone();
+        }
+    }
+
+    Ok(Some(cache))
+}
+
+pub fn save_cache(config: &Config, cache: &DataCache) -> Result<()> {
+    if config.demo {
+        return Ok(());
+    }
+
+    let cache_path = Config::cache_path()?;
+    Config::ensure_config_dir_exists(&cache_path)?;
+
+    let temp_path = cache_path.with_extension("json.tmp");
+    fs::write(&temp_path, serde_json::to_string(cache)?)?;
+    fs::rename(temp_path, cache_path)?;
+
+    Ok(())
+}
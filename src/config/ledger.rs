@@ -0,0 +1,49 @@
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+
+use crate::config::settings::Config;
+use crate::error::Result;
+use crate::models::ledger::{merge_ledger_entry, LedgerEntry};
+
+pub fn load_ledger(config: &Config) -> Result<Vec<LedgerEntry>> {
+    if config.demo {
+        return Ok(Vec::new());
+    }
+
+    let content = match fs::read_to_string(Config::ledger_path()?) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut ledger = Vec::new();
+    for entry in content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<LedgerEntry>(line).ok())
+        .filter(|entry| entry.scope.api_url == config.api_url())
+    {
+        merge_ledger_entry(&mut ledger, entry);
+    }
+
+    Ok(ledger)
+}
+
+pub fn append_ledger(config: &Config, entries: &[LedgerEntry]) -> Result<()> {
+    if config.demo || entries.is_empty() {
+        return Ok(());
+    }
+
+    let ledger_path = Config::ledger_path()?;
+    Config::ensure_config_dir_exists(&ledger_path)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(ledger_path)?;
+
+    for entry in entries {
+        writeln!(file, "{}", serde_json::to_string(entry)?)?;
+    }
+
+    Ok(())
+}
@@ -1,2 +1,5 @@
+pub mod cache;
+pub mod ledger;
+pub mod parking_log;
 pub mod settings;
 pub mod store;
@@ -0,0 +1,48 @@
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::Path;
+
+use crate::config::settings::Config;
+use crate::error::Result;
+use crate::models::parking::ParkingRegistration;
+
+pub fn load_parking_log(config: &Config) -> Result<Vec<ParkingRegistration>> {
+    if config.demo {
+        return Ok(Vec::new());
+    }
+
+    read_parking_log(&config.parking_log_path()?)
+}
+
+pub fn append_parking_log(config: &Config, registration: &ParkingRegistration) -> Result<()> {
+    if config.demo {
+        return Ok(());
+    }
+
+    write_parking_log(&config.parking_log_path()?, registration)
+}
+
+pub fn read_parking_log(log_path: &Path) -> Result<Vec<ParkingRegistration>> {
+    let content = match fs::read_to_string(log_path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e.into()),
+    };
+
+    Ok(content
+        .lines()
+        .filter_map(|line| serde_json::from_str(line).ok())
+        .collect())
+}
+
+pub fn write_parking_log(log_path: &Path, registration: &ParkingRegistration) -> Result<()> {
+    Config::ensure_config_dir_exists(log_path)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_path)?;
+    writeln!(file, "{}", serde_json::to_string(registration)?)?;
+
+    Ok(())
+}
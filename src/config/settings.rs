@@ -1,7 +1,23 @@
 use crate::error::{AppError, Result};
+use crate::models::parking::{ParkingProfile, ParkingSchedule, Vehicle};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_API_URL: &str = "https://stregsystem.fklub.dk/api";
+
+pub const PARKING_LOG_FILE: &str = ".stregsystemet-parking.jsonl";
+
+pub const CACHE_DIR: &str = "stregsystemet-tui";
+pub const CACHE_FILE: &str = "cache.json";
+
+pub const DATA_DIR: &str = "stregsystemet-tui";
+pub const LEDGER_FILE: &str = "ledger.jsonl";
+
+pub const API_URL_ENV: &str = "STUI_API_URL";
+pub const ROOM_ID_ENV: &str = "STUI_ROOM_ID";
+pub const USERNAME_ENV: &str = "STUI_USERNAME";
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Config {
@@ -10,8 +26,143 @@ pub struct Config {
     #[serde(default = "default_room_id")]
     pub room_id: u32,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+
     pub phone_number: Option<String>,
     pub license_plate: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parking_profile: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub favorites: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub phone_numbers: Vec<String>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vehicles: Vec<Vehicle>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parking_profiles: Vec<ParkingProfile>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parking_schedule: Option<ParkingSchedule>,
+
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub dry_run: bool,
+
+    #[serde(default, skip_serializing_if = "NetworkConfig::is_default")]
+    pub network: NetworkConfig,
+
+    #[serde(skip)]
+    pub path: Option<PathBuf>,
+
+    #[serde(skip)]
+    pub overrides: ConfigOverrides,
+
+    #[serde(skip)]
+    pub demo: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct NetworkConfig {
+    #[serde(default = "default_connect_timeout")]
+    pub connect_timeout: u64,
+
+    #[serde(default = "default_read_timeout")]
+    pub read_timeout: u64,
+
+    #[serde(default = "default_retries")]
+    pub retries: u32,
+
+    #[serde(default = "default_refresh_interval")]
+    pub refresh_interval: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: default_connect_timeout(),
+            read_timeout: default_read_timeout(),
+            retries: default_retries(),
+            refresh_interval: default_refresh_interval(),
+        }
+    }
+}
+
+impl NetworkConfig {
+    pub fn connect_timeout(&self) -> Duration {
+        Duration::from_secs(self.connect_timeout)
+    }
+
+    pub fn read_timeout(&self) -> Duration {
+        Duration::from_secs(self.read_timeout)
+    }
+
+    pub fn refresh_interval(&self) -> Option<Duration> {
+        (self.refresh_interval > 0).then(|| Duration::from_secs(self.refresh_interval))
+    }
+
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+fn default_connect_timeout() -> u64 {
+    5
+}
+
+fn default_read_timeout() -> u64 {
+    15
+}
+
+fn default_retries() -> u32 {
+    3
+}
+
+fn default_refresh_interval() -> u64 {
+    60
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ConfigOverrides {
+    pub username: Option<String>,
+    pub room_id: Option<u32>,
+    pub api_url: Option<String>,
+    pub dry_run: bool,
+}
+
+impl ConfigOverrides {
+    pub fn or_env(self) -> Result<Self> {
+        self.or_env_with(|name| std::env::var(name).ok())
+    }
+
+    fn or_env_with(self, env: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let non_empty_env = |name: &str| {
+            env(name)
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let room_id = match self.room_id {
+            Some(room_id) => Some(room_id),
+            None => match non_empty_env(ROOM_ID_ENV) {
+                Some(value) => Some(value.parse::<u32>().map_err(|_| {
+                    AppError::Config(format!("{ROOM_ID_ENV} must be a number, got '{value}'"))
+                })?),
+                None => None,
+            },
+        };
+
+        Ok(Self {
+            username: self.username.or_else(|| non_empty_env(USERNAME_ENV)),
+            room_id,
+            api_url: self.api_url.or_else(|| non_empty_env(API_URL_ENV)),
+            dry_run: self.dry_run,
+        })
+    }
 }
 
 fn default_room_id() -> u32 {
@@ -23,15 +174,122 @@ impl Default for Config {
         Self {
             username: None,
             room_id: default_room_id(),
+            api_url: None,
             phone_number: None,
             license_plate: None,
+            parking_profile: None,
+            favorites: Vec::new(),
+            phone_numbers: Vec::new(),
+            vehicles: Vec::new(),
+            parking_profiles: Vec::new(),
+            parking_schedule: None,
+            dry_run: false,
+            network: NetworkConfig::default(),
+            path: None,
+            overrides: ConfigOverrides::default(),
+            demo: false,
         }
     }
 }
 
 impl Config {
-    pub fn get_api_url() -> String {
-        "https://stregsystem.fklub.dk/api".to_string()
+    pub fn username(&self) -> Option<&String> {
+        self.overrides.username.as_ref().or(self.username.as_ref())
+    }
+
+    pub fn set_username(&mut self, username: String) {
+        self.username = Some(username);
+        self.overrides.username = None;
+    }
+
+    pub fn room_id(&self) -> u32 {
+        self.overrides.room_id.unwrap_or(self.room_id)
+    }
+
+    pub fn api_url(&self) -> String {
+        self.overrides
+            .api_url
+            .as_deref()
+            .or(self.api_url.as_deref())
+            .unwrap_or(DEFAULT_API_URL)
+            .trim_end_matches('/')
+            .to_string()
+    }
+
+    pub fn dry_run(&self) -> bool {
+        self.dry_run || self.overrides.dry_run
+    }
+
+    pub fn is_favorite(&self, product_id: &str) -> bool {
+        self.favorites.iter().any(|id| id == product_id)
+    }
+
+    pub fn toggle_favorite(&mut self, product_id: &str) -> bool {
+        if self.is_favorite(product_id) {
+            self.favorites.retain(|id| id != product_id);
+            false
+        } else {
+            self.favorites.push(product_id.to_string());
+            true
+        }
+    }
+
+    pub fn migrate_legacy_parking(&mut self) {
+        if let (true, Some(phone_number)) = (self.phone_numbers.is_empty(), &self.phone_number) {
+            self.phone_numbers.push(phone_number.clone());
+        }
+
+        if let (true, Some(plate)) = (self.vehicles.is_empty(), &self.license_plate) {
+            self.vehicles.push(Vehicle {
+                plate: plate.clone(),
+                country: "DK".to_string(),
+                nickname: None,
+            });
+        }
+    }
+
+    pub fn parking_profiles(&self) -> Vec<ParkingProfile> {
+        if self.parking_profiles.is_empty() {
+            vec![ParkingProfile::default()]
+        } else {
+            self.parking_profiles.clone()
+        }
+    }
+
+    pub fn selected_parking_profile(&self) -> usize {
+        self.parking_profile
+            .as_ref()
+            .and_then(|name| {
+                self.parking_profiles()
+                    .iter()
+                    .position(|profile| &profile.name == name)
+            })
+            .unwrap_or(0)
+    }
+
+    pub fn file_path(&self) -> Result<PathBuf> {
+        match self.path {
+            Some(ref path) => Ok(path.clone()),
+            None => Self::get_config_path(),
+        }
+    }
+
+    pub fn parking_log_path(&self) -> Result<PathBuf> {
+        Ok(self.file_path()?.with_file_name(PARKING_LOG_FILE))
+    }
+
+    pub fn cache_path() -> Result<PathBuf> {
+        let cache_dir = dirs::cache_dir()
+            .ok_or_else(|| AppError::Config("Could not find cache directory".to_string()))?;
+
+        Ok(cache_dir.join(CACHE_DIR).join(CACHE_FILE))
+    }
+
+    pub fn ledger_path() -> Result<PathBuf> {
+        let data_dir = dirs::data_dir()
+            .ok_or_else(|| AppError::Config("Could not find data directory".to_string()))?;
+
+        Ok(data_dir.join(DATA_DIR).join(LEDGER_FILE))
     }
 
     pub fn get_config_path() -> Result<PathBuf> {
@@ -42,10 +300,9 @@ impl Config {
         Ok(config_dir.join(".stregsystemet.toml"))
     }
 
-    pub fn ensure_config_dir_exists() -> Result<()> {
-        let config_path = Self::get_config_path()?;
+    pub fn ensure_config_dir_exists(config_path: &Path) -> Result<()> {
         if let Some(parent) = config_path.parent() {
-            if !parent.exists() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
                 fs::create_dir_all(parent).map_err(|e| {
                     AppError::Config(format!("Failed to create config directory: {e}"))
                 })?;
@@ -68,6 +325,98 @@ impl Config {
             }
         }
 
+        for profile in &self.parking_profiles {
+            if profile.name.trim().is_empty() || profile.zone_key.trim().is_empty() {
+                return Err(AppError::Config(
+                    "Parking profiles need a name and a zone_key".to_string(),
+                ));
+            }
+
+            if profile.duration == 0 {
+                return Err(AppError::Config(format!(
+                    "Parking profile '{}' must have a duration above 0 minutes",
+                    profile.name
+                )));
+            }
+        }
+
+        if self.network.connect_timeout == 0 || self.network.read_timeout == 0 {
+            return Err(AppError::Config(
+                "Network timeouts must be at least 1 second".to_string(),
+            ));
+        }
+
+        if let Some(ref schedule) = self.parking_schedule {
+            if schedule.weekdays.is_empty() || schedule.times.is_empty() {
+                return Err(AppError::Config(
+                    "The parking schedule needs at least one weekday and one time".to_string(),
+                ));
+            }
+        }
+
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        move |name| vars.get(name).cloned()
+    }
+
+    #[test]
+    fn flags_take_precedence_over_env() {
+        let flags = ConfigOverrides {
+            room_id: Some(10),
+            api_url: Some("http://flag/api".to_string()),
+            ..ConfigOverrides::default()
+        };
+        let overrides = flags
+            .or_env_with(env(&[
+                (ROOM_ID_ENV, "20"),
+                (API_URL_ENV, "http://env/api"),
+                (USERNAME_ENV, "alice"),
+            ]))
+            .unwrap();
+
+        assert_eq!(overrides.room_id, Some(10));
+        assert_eq!(overrides.api_url.as_deref(), Some("http://flag/api"));
+        assert_eq!(overrides.username.as_deref(), Some("alice"));
+    }
+
+    #[test]
+    fn malformed_env_is_ignored_when_the_flag_is_given() {
+        let flags = ConfigOverrides {
+            room_id: Some(10),
+            ..ConfigOverrides::default()
+        };
+        let overrides = flags.or_env_with(env(&[(ROOM_ID_ENV, "ten")])).unwrap();
+
+        assert_eq!(overrides.room_id, Some(10));
+    }
+
+    #[test]
+    fn malformed_env_is_an_error_without_the_flag() {
+        let result = ConfigOverrides::default().or_env_with(env(&[(ROOM_ID_ENV, "ten")]));
+
+        assert!(matches!(result, Err(AppError::Config(_))));
+    }
+
+    #[test]
+    fn blank_env_values_are_ignored() {
+        let overrides = ConfigOverrides::default()
+            .or_env_with(env(&[(ROOM_ID_ENV, "  "), (API_URL_ENV, "")]))
+            .unwrap();
+
+        assert_eq!(overrides.room_id, None);
+        assert_eq!(overrides.api_url, None);
+    }
+}
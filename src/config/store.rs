@@ -1,25 +1,45 @@
 use std::fs;
+use std::path::Path;
 
 use crate::config::settings::Config;
 use crate::error::{AppError, Result};
 
 pub fn load_or_create_config() -> Result<Config> {
-    let config_path = Config::get_config_path()?;
+    load_or_create_config_at(&Config::get_config_path()?)
+}
+
+pub fn load_or_create_config_at(config_path: &Path) -> Result<Config> {
+    load_config_file(config_path).map_err(|e| match e {
+        AppError::Config(message) => {
+            AppError::Config(format!("{}: {message}", config_path.display()))
+        }
+        AppError::Io(err) => {
+            AppError::Config(format!("Could not read {}: {err}", config_path.display()))
+        }
+        e => e,
+    })
+}
 
+fn load_config_file(config_path: &Path) -> Result<Config> {
     if !config_path.exists() {
-        Config::ensure_config_dir_exists()?;
+        Config::ensure_config_dir_exists(config_path)?;
 
-        let default_config = Config::default();
-        fs::write(&config_path, default_config.to_toml_string()?)?;
+        let default_config = Config {
+            path: Some(config_path.to_path_buf()),
+            ..Config::default()
+        };
+        fs::write(config_path, default_config.to_toml_string()?)?;
 
         return Ok(default_config);
     }
 
-    let content = fs::read_to_string(&config_path)?;
-    let config: Config = toml::from_str(&content)
+    let content = fs::read_to_string(config_path)?;
+    let mut config: Config = toml::from_str(&content)
         .map_err(|e| AppError::Config(format!("Failed to parse config: {e}")))?;
 
     config.validate()?;
+    config.migrate_legacy_parking();
+    config.path = Some(config_path.to_path_buf());
 
     Ok(config)
 }
@@ -27,9 +47,13 @@ pub fn load_or_create_config() -> Result<Config> {
 pub fn save_config(config: &Config) -> Result<()> {
     config.validate()?;
 
-    Config::ensure_config_dir_exists()?;
+    if config.demo {
+        return Ok(());
+    }
+
+    let config_path = config.file_path()?;
+    Config::ensure_config_dir_exists(&config_path)?;
 
-    let config_path = Config::get_config_path()?;
     fs::write(&config_path, config.to_toml_string()?)?;
 
     Ok(())
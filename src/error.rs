@@ -1,12 +1,27 @@
+use reqwest::StatusCode;
 use std::fmt;
 
+use crate::api::dry_run::DryRunRequest;
+
 #[derive(Debug)]
 pub enum AppError {
     Io(std::io::Error),
     Network(reqwest::Error),
+    Timeout {
+        context: &'static str,
+    },
+    HttpStatus {
+        context: &'static str,
+        status: StatusCode,
+    },
+    Decode {
+        context: &'static str,
+        source: serde_json::Error,
+    },
     Config(String),
     Api(String),
     Input(String),
+    DryRun(Box<DryRunRequest>),
 }
 
 impl std::error::Error for AppError {}
@@ -16,9 +31,30 @@ impl fmt::Display for AppError {
         match self {
             AppError::Io(err) => write!(f, "I/O error: {err}"),
             AppError::Network(err) => write!(f, "Network error: {err}"),
+            AppError::Timeout { context } => write!(f, "{context}: the request timed out"),
+            AppError::HttpStatus { context, status } => {
+                write!(f, "{context}: HTTP status {status}")
+            }
+            AppError::Decode { context, source } => {
+                write!(f, "{context}: unexpected response ({source})")
+            }
             AppError::Config(err) => write!(f, "Configuration error: {err}"),
             AppError::Api(err) => write!(f, "API error: {err}"),
             AppError::Input(err) => write!(f, "Input error: {err}"),
+            AppError::DryRun(request) => write!(f, "Dry run, nothing was sent: {request}"),
+        }
+    }
+}
+
+impl AppError {
+    pub fn is_transient(&self) -> bool {
+        match self {
+            AppError::Network(err) => err.is_connect() || err.is_request(),
+            AppError::Timeout { .. } => true,
+            AppError::HttpStatus { status, .. } => {
+                status.is_server_error() || *status == StatusCode::TOO_MANY_REQUESTS
+            }
+            _ => false,
         }
     }
 }
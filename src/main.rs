@@ -1,3 +1,4 @@
+use clap::Parser;
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -5,36 +6,75 @@ use crossterm::{
 };
 use ratatui::backend::CrosstermBackend;
 use std::io::{self, Write};
+use std::process::ExitCode;
 
 mod api;
 mod app;
+mod cli;
 mod config;
 mod error;
 mod models;
 mod ui;
 mod utils;
 
+use crate::api::backend::create_backend;
+use crate::cli::args::Cli;
 use crate::ui::events::EventHandler;
 use app::state::AppState;
-use config::store::load_or_create_config;
+use config::cache::load_cache;
+use config::ledger::load_ledger;
+use config::parking_log::load_parking_log;
+use config::settings::Config;
 use std::sync::{Arc, Mutex};
 
 #[tokio::main]
-async fn main() -> io::Result<()> {
+async fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let output_format = cli.output_format();
+    let config = cli.load_config();
+
+    if let Some(command) = cli.command {
+        return cli::commands::run(command, output_format, config).await;
+    }
+
+    let config = match config {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    match run_tui(config).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Error: {err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+async fn run_tui(config: Config) -> io::Result<()> {
+    let api_client = create_backend(&config).map_err(|e| io::Error::other(e.to_string()))?;
+
     enable_raw_mode()?;
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = ratatui::Terminal::new(backend)?;
 
-    let config_result = load_or_create_config();
-    let config = config_result.unwrap_or_default();
-
-    let app_state = AppState::new(config);
+    let mut app_state = AppState::new(config);
+    app_state.parking_log = load_parking_log(&app_state.config).unwrap_or_default();
+    app_state.ledger = load_ledger(&app_state.config).unwrap_or_default();
+    if let Ok(Some(cache)) = load_cache(&app_state.config) {
+        app_state.restore_cache(cache);
+    }
 
     let shutdown_flag = Arc::new(Mutex::new(false));
 
-    let mut event_handler = EventHandler::new(&mut terminal, app_state, shutdown_flag.clone());
+    let mut event_handler =
+        EventHandler::new(&mut terminal, app_state, api_client, shutdown_flag.clone());
     let result = event_handler.run().await;
 
     cleanup_terminal(&mut terminal)?;
@@ -42,7 +82,6 @@ async fn main() -> io::Result<()> {
     if let Err(err) = result {
         let _ = cleanup_terminal(&mut terminal);
 
-        eprintln!("Error: {err:?}");
         return Err(io::Error::other(err.to_string()));
     }
 
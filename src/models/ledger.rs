@@ -0,0 +1,222 @@
+use chrono::Duration;
+use serde::{Deserialize, Serialize};
+
+use crate::models::sale::Sale;
+
+const LOCAL_MATCH_WINDOW_MINUTES: i64 = 10;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LedgerScope {
+    pub api_url: String,
+    pub username: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    #[serde(flatten)]
+    pub scope: LedgerScope,
+
+    #[serde(flatten)]
+    pub sale: Sale,
+
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub local: bool,
+}
+
+impl LedgerEntry {
+    pub fn fetched(scope: &LedgerScope, sale: Sale) -> Self {
+        Self {
+            scope: scope.clone(),
+            sale,
+            local: false,
+        }
+    }
+
+    pub fn local(scope: &LedgerScope, sale: Sale) -> Self {
+        Self {
+            scope: scope.clone(),
+            sale,
+            local: true,
+        }
+    }
+
+    fn is_same_sale(&self, other: &LedgerEntry) -> bool {
+        self.scope == other.scope
+            && self.sale.product == other.sale.product
+            && self.sale.price == other.sale.price
+            && match (self.sale.local_time(), other.sale.local_time()) {
+                (Some(a), Some(b)) => a == b,
+                _ => self.sale.timestamp == other.sale.timestamp,
+            }
+    }
+
+    fn confirms(&self, local: &LedgerEntry) -> bool {
+        if self.local || !local.local || self.scope != local.scope {
+            return false;
+        }
+
+        if self.sale.product != local.sale.product || self.sale.price != local.sale.price {
+            return false;
+        }
+
+        match (self.sale.local_time(), local.sale.local_time()) {
+            (Some(a), Some(b)) => (a - b).abs() <= Duration::minutes(LOCAL_MATCH_WINDOW_MINUTES),
+            _ => false,
+        }
+    }
+}
+
+pub fn merge_ledger_entry(ledger: &mut Vec<LedgerEntry>, entry: LedgerEntry) -> bool {
+    if entry.local {
+        ledger.push(entry);
+        return true;
+    }
+
+    if ledger.iter().any(|existing| existing.is_same_sale(&entry)) {
+        return false;
+    }
+
+    match ledger.iter().position(|existing| entry.confirms(existing)) {
+        Some(index) => ledger[index] = entry,
+        None => ledger.push(entry),
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::money::Money;
+
+    fn scope(api_url: &str, username: &str) -> LedgerScope {
+        LedgerScope {
+            api_url: api_url.to_string(),
+            username: username.to_string(),
+        }
+    }
+
+    fn sale(timestamp: &str, product: &str, price: i32) -> Sale {
+        Sale {
+            timestamp: timestamp.to_string(),
+            product: product.to_string(),
+            price: Money::new(price),
+        }
+    }
+
+    fn alice() -> LedgerScope {
+        scope("https://stregsystem.fklub.dk/api", "alice")
+    }
+
+    #[test]
+    fn fetched_sale_is_recorded_once() {
+        let mut ledger = Vec::new();
+        let entry = LedgerEntry::fetched(&alice(), sale("2026-10-17T12:00:00+02:00", "Cola", 1000));
+
+        assert!(merge_ledger_entry(&mut ledger, entry.clone()));
+        assert!(!merge_ledger_entry(&mut ledger, entry));
+        assert_eq!(ledger.len(), 1);
+    }
+
+    #[test]
+    fn same_instant_in_another_offset_is_the_same_sale() {
+        let mut ledger = Vec::new();
+        let utc = sale("2026-10-17T10:00:00Z", "Cola", 1000);
+        let local = sale("2026-10-17T12:00:00+02:00", "Cola", 1000);
+
+        merge_ledger_entry(&mut ledger, LedgerEntry::fetched(&alice(), utc));
+
+        assert!(!merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::fetched(&alice(), local)
+        ));
+    }
+
+    #[test]
+    fn sales_are_kept_apart_by_scope() {
+        let mut ledger = Vec::new();
+        let cola = sale("2026-10-17T12:00:00+02:00", "Cola", 1000);
+
+        for scope in [
+            alice(),
+            scope("https://stregsystem.fklub.dk/api", "bob"),
+            scope("http://localhost:8000/api", "alice"),
+        ] {
+            assert!(merge_ledger_entry(
+                &mut ledger,
+                LedgerEntry::fetched(&scope, cola.clone())
+            ));
+        }
+
+        assert_eq!(ledger.len(), 3);
+    }
+
+    #[test]
+    fn fetched_sale_confirms_local_sale_within_window() {
+        let mut ledger = Vec::new();
+        merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::local(&alice(), sale("2026-10-17T12:00:00+02:00", "Cola", 1000)),
+        );
+
+        assert!(merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::fetched(&alice(), sale("2026-10-17T12:09:59+02:00", "Cola", 1000)),
+        ));
+
+        assert_eq!(ledger.len(), 1);
+        assert!(!ledger[0].local);
+        assert_eq!(ledger[0].sale.timestamp, "2026-10-17T12:09:59+02:00");
+    }
+
+    #[test]
+    fn fetched_sale_outside_window_does_not_confirm() {
+        let mut ledger = Vec::new();
+        merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::local(&alice(), sale("2026-10-17T12:00:00+02:00", "Cola", 1000)),
+        );
+        merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::fetched(&alice(), sale("2026-10-17T12:10:01+02:00", "Cola", 1000)),
+        );
+
+        assert_eq!(ledger.len(), 2);
+        assert!(ledger[0].local);
+    }
+
+    #[test]
+    fn only_matching_product_and_price_confirm() {
+        let local = LedgerEntry::local(&alice(), sale("2026-10-17T12:00:00+02:00", "Cola", 1000));
+
+        for fetched in [
+            sale("2026-10-17T12:01:00+02:00", "Beer", 1000),
+            sale("2026-10-17T12:01:00+02:00", "Cola", 1200),
+        ] {
+            assert!(!LedgerEntry::fetched(&alice(), fetched).confirms(&local));
+        }
+
+        let fetched =
+            LedgerEntry::fetched(&alice(), sale("2026-10-17T12:01:00+02:00", "Cola", 1000));
+        let other_user = scope("https://stregsystem.fklub.dk/api", "bob");
+        assert!(!fetched.confirms(&LedgerEntry::fetched(&alice(), local.sale.clone())));
+        assert!(!fetched.confirms(&LedgerEntry::local(&other_user, local.sale.clone())));
+        assert!(fetched.confirms(&local));
+    }
+
+    #[test]
+    fn repeated_local_purchases_are_all_kept() {
+        let mut ledger = Vec::new();
+        let cola = sale("2026-10-17T12:00:00+02:00", "Cola", 1000);
+
+        assert!(merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::local(&alice(), cola.clone())
+        ));
+        assert!(merge_ledger_entry(
+            &mut ledger,
+            LedgerEntry::local(&alice(), cola)
+        ));
+        assert_eq!(ledger.len(), 2);
+    }
+}
@@ -1,3 +1,7 @@
+pub mod ledger;
 pub mod member;
+pub mod parking;
+pub mod permit;
 pub mod product;
+pub mod purchase;
 pub mod sale;
@@ -0,0 +1,240 @@
+use chrono::{DateTime, Datelike, Duration, Local, NaiveTime, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::models::permit::ParkingPermit;
+
+pub const DEFAULT_PARKING_UID: &str = "12cdf204-d969-469a-9bd5-c1f1fc59ee34";
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ParkingProfile {
+    pub name: String,
+    pub zone_id: u32,
+    pub zone_key: String,
+
+    #[serde(default = "default_duration")]
+    pub duration: u32,
+
+    #[serde(default = "default_country")]
+    pub country: String,
+
+    #[serde(default = "default_phone_prefix")]
+    pub phone_prefix: String,
+
+    #[serde(default = "default_uid")]
+    pub uid: String,
+}
+
+fn default_duration() -> u32 {
+    600
+}
+
+fn default_country() -> String {
+    "DK".to_string()
+}
+
+fn default_phone_prefix() -> String {
+    "45".to_string()
+}
+
+fn default_uid() -> String {
+    DEFAULT_PARKING_UID.to_string()
+}
+
+impl Default for ParkingProfile {
+    fn default() -> Self {
+        Self {
+            name: "AAU Zone 4688".to_string(),
+            zone_id: 1956,
+            zone_key: "ADK-4688".to_string(),
+            duration: default_duration(),
+            country: default_country(),
+            phone_prefix: default_phone_prefix(),
+            uid: default_uid(),
+        }
+    }
+}
+
+impl ParkingProfile {
+    pub fn formatted_duration(&self) -> String {
+        let (hours, minutes) = (self.duration / 60, self.duration % 60);
+
+        match (hours, minutes) {
+            (1, 0) => "1 hour".to_string(),
+            (hours, 0) => format!("{hours} hours"),
+            (0, minutes) => format!("{minutes} minutes"),
+            (hours, minutes) => format!("{hours}h {minutes:02}m"),
+        }
+    }
+
+    pub fn phone_length(&self) -> (usize, usize) {
+        if self.phone_prefix == "45" {
+            (8, 8)
+        } else {
+            (4, 15)
+        }
+    }
+
+    pub fn summary(&self) -> String {
+        format!(
+            "{} ({}, {}, +{})",
+            self.name,
+            self.formatted_duration(),
+            self.country,
+            self.phone_prefix
+        )
+    }
+
+    pub fn permit_request(&self, vehicle: &Vehicle, phone_number: &str) -> ParkingPermitRequest {
+        ParkingPermitRequest {
+            email: String::new(),
+            phone_number: format!("{}{}", self.phone_prefix, phone_number),
+            vehicle_registration_country: vehicle.country.clone(),
+            duration: self.duration,
+            vehicle_registration: vehicle.plate.clone(),
+            parking_areas: vec![ParkingArea {
+                parking_area_id: self.zone_id,
+                parking_area_key: self.zone_key.clone(),
+            }],
+            uid: self.uid.clone(),
+            lang: "da".to_string(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Vehicle {
+    pub plate: String,
+
+    #[serde(default = "default_country")]
+    pub country: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nickname: Option<String>,
+}
+
+impl Vehicle {
+    pub fn label(&self) -> String {
+        match self.nickname {
+            Some(ref nickname) => format!("{nickname} ({}, {})", self.plate, self.country),
+            None => format!("{} ({})", self.plate, self.country),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ParkingPermitRequest {
+    pub email: String,
+    #[serde(rename = "PhoneNumber")]
+    pub phone_number: String,
+    #[serde(rename = "VehicleRegistrationCountry")]
+    pub vehicle_registration_country: String,
+    #[serde(rename = "Duration")]
+    pub duration: u32,
+    #[serde(rename = "VehicleRegistration")]
+    pub vehicle_registration: String,
+    #[serde(rename = "parkingAreas")]
+    pub parking_areas: Vec<ParkingArea>,
+    #[serde(rename = "UId")]
+    pub uid: String,
+    #[serde(rename = "Lang")]
+    pub lang: String,
+}
+
+#[derive(Serialize, Debug, Clone, PartialEq)]
+pub struct ParkingArea {
+    #[serde(rename = "ParkingAreaId")]
+    pub parking_area_id: u32,
+    #[serde(rename = "ParkingAreaKey")]
+    pub parking_area_key: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ParkingRegistration {
+    pub registered_at: DateTime<Local>,
+    pub profile: String,
+    pub zone_key: String,
+    pub plate: String,
+    pub country: String,
+    pub phone_number: String,
+    pub duration: u32,
+
+    #[serde(default)]
+    pub permit: ParkingPermit,
+}
+
+impl ParkingRegistration {
+    pub fn new(profile: &str, request: &ParkingPermitRequest, permit: ParkingPermit) -> Self {
+        Self {
+            registered_at: Local::now(),
+            profile: profile.to_string(),
+            zone_key: request
+                .parking_areas
+                .first()
+                .map(|area| area.parking_area_key.clone())
+                .unwrap_or_default(),
+            plate: request.vehicle_registration.clone(),
+            country: request.vehicle_registration_country.clone(),
+            phone_number: request.phone_number.clone(),
+            duration: request.duration,
+            permit,
+        }
+    }
+
+    pub fn expires_at(&self) -> DateTime<Local> {
+        self.permit
+            .valid_to
+            .unwrap_or_else(|| self.registered_at + Duration::minutes(i64::from(self.duration)))
+    }
+
+    pub fn remaining(&self, now: DateTime<Local>) -> Option<Duration> {
+        let remaining = self.expires_at() - now;
+        (remaining > Duration::zero()).then_some(remaining)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ParkingSchedule {
+    pub weekdays: Vec<Weekday>,
+    pub times: Vec<NaiveTime>,
+
+    #[serde(default = "default_schedule_window")]
+    pub window: u32,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vehicle: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub phone_number: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hook: Option<String>,
+}
+
+fn default_schedule_window() -> u32 {
+    60
+}
+
+impl ParkingSchedule {
+    pub fn latest_slot(&self, now: DateTime<Local>) -> Option<DateTime<Local>> {
+        if !self.weekdays.contains(&now.weekday()) {
+            return None;
+        }
+
+        let time = self
+            .times
+            .iter()
+            .filter(|time| **time <= now.time())
+            .max()?;
+
+        let slot = now
+            .date_naive()
+            .and_time(*time)
+            .and_local_timezone(Local)
+            .earliest()?;
+
+        (now - slot <= Duration::minutes(i64::from(self.window))).then_some(slot)
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt;
+
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone};
+use serde::{Deserialize, Deserializer, Serialize};
+
+const MAX_UNRECOGNIZED_RESPONSE: usize = 1000;
+const UNRECOGNIZED_RESPONSE_WARNING: &str = "The reply could not be read, check your SMS";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct ParkingPermit {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_from: Option<DateTime<Local>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub valid_to: Option<DateTime<Local>>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub unrecognized_response: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParkingFailure {
+    pub message: String,
+}
+
+impl fmt::Display for ParkingFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Parking was rejected: {}", self.message)
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct ConfirmResponse {
+    #[serde(default)]
+    success: Option<bool>,
+
+    #[serde(default)]
+    error_code: Option<String>,
+
+    #[serde(default)]
+    error_message: Option<String>,
+
+    #[serde(default)]
+    permit: Option<ConfirmedPermit>,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "PascalCase")]
+struct ConfirmedPermit {
+    permit_id: PermitId,
+
+    #[serde(deserialize_with = "provider_time")]
+    valid_from: DateTime<Local>,
+
+    #[serde(deserialize_with = "provider_time")]
+    valid_to: DateTime<Local>,
+
+    parking_area_key: String,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(untagged)]
+enum PermitId {
+    Number(u64),
+    Text(String),
+}
+
+impl ParkingPermit {
+    // The permit is issued once the provider answers with a 2xx, so only an
+    // explicit rejection is an error. A reply we can't read is kept for the log.
+    pub fn from_response(body: &str) -> std::result::Result<Self, ParkingFailure> {
+        let response = serde_json::from_str::<ConfirmResponse>(body).ok();
+
+        if let Some(failure) = response.as_ref().and_then(ParkingFailure::from_confirm) {
+            return Err(failure);
+        }
+
+        match response.and_then(|response| response.permit) {
+            Some(permit) => Ok(Self {
+                id: Some(match permit.permit_id {
+                    PermitId::Number(id) => id.to_string(),
+                    PermitId::Text(id) => id,
+                }),
+                valid_from: Some(permit.valid_from),
+                valid_to: Some(permit.valid_to),
+                zone: Some(permit.parking_area_key),
+                unrecognized_response: None,
+            }),
+            None => Ok(Self {
+                unrecognized_response: Some(
+                    body.trim()
+                        .chars()
+                        .take(MAX_UNRECOGNIZED_RESPONSE)
+                        .collect(),
+                ),
+                ..Self::default()
+            }),
+        }
+    }
+
+    pub fn warning(&self) -> Option<&'static str> {
+        self.unrecognized_response
+            .is_some()
+            .then_some(UNRECOGNIZED_RESPONSE_WARNING)
+    }
+}
+
+impl ParkingFailure {
+    pub fn from_response(body: &str) -> Option<Self> {
+        let response = serde_json::from_str::<ConfirmResponse>(body).ok()?;
+
+        Self::from_confirm(&response).or_else(|| {
+            response
+                .error_message
+                .or(response.error_code)
+                .map(|message| Self { message })
+        })
+    }
+
+    fn from_confirm(response: &ConfirmResponse) -> Option<Self> {
+        if response.success != Some(false) {
+            return None;
+        }
+
+        let message = response
+            .error_message
+            .clone()
+            .or_else(|| response.error_code.clone())
+            .unwrap_or_else(|| "no reason given".to_string());
+
+        Some(Self { message })
+    }
+}
+
+fn provider_time<'de, D>(deserializer: D) -> std::result::Result<DateTime<Local>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(&value) {
+        return Ok(timestamp.with_timezone(&Local));
+    }
+
+    NaiveDateTime::parse_from_str(&value, "%Y-%m-%dT%H:%M:%S%.f")
+        .ok()
+        .and_then(|timestamp| Local.from_local_datetime(&timestamp).earliest())
+        .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp: {value}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIRMED: &str = r#"{
+        "Success": true,
+        "ErrorCode": null,
+        "ErrorMessage": null,
+        "Permit": {
+            "PermitId": 48213377,
+            "ValidFrom": "2026-10-17T08:00:00",
+            "ValidTo": "2026-10-17T18:00:00",
+            "ParkingAreaKey": "ADK-4688"
+        }
+    }"#;
+
+    const REJECTED: &str = r#"{
+        "Success": false,
+        "ErrorCode": "1",
+        "ErrorMessage": "Vehicle registration is not valid",
+        "Permit": null
+    }"#;
+
+    #[test]
+    fn confirmed_permit_is_parsed() {
+        let permit = ParkingPermit::from_response(CONFIRMED).unwrap();
+
+        assert_eq!(permit.id.as_deref(), Some("48213377"));
+        assert_eq!(permit.zone.as_deref(), Some("ADK-4688"));
+        assert_eq!(
+            permit.valid_to.map(|time| time.format("%H:%M").to_string()),
+            Some("18:00".to_string())
+        );
+        assert_eq!(permit.warning(), None);
+    }
+
+    #[test]
+    fn rejection_is_an_error() {
+        let failure = ParkingPermit::from_response(REJECTED).unwrap_err();
+
+        assert_eq!(failure.message, "Vehicle registration is not valid");
+        assert_eq!(
+            ParkingFailure::from_response(REJECTED).map(|failure| failure.message),
+            Some("Vehicle registration is not valid".to_string())
+        );
+    }
+
+    #[test]
+    fn rejection_without_a_message_still_fails() {
+        let failure = ParkingPermit::from_response(r#"{"Success": false}"#).unwrap_err();
+
+        assert_eq!(failure.message, "no reason given");
+    }
+
+    #[test]
+    fn unreadable_success_keeps_the_body_and_warns() {
+        for body in ["", "OK", "[]", "{}", r#"{"success": true, "id": 7}"#] {
+            let permit = ParkingPermit::from_response(body).unwrap();
+
+            assert_eq!(permit.id, None, "{body:?}");
+            assert_eq!(permit.unrecognized_response.as_deref(), Some(body));
+            assert!(permit.warning().is_some());
+        }
+    }
+
+    #[test]
+    fn error_status_body_is_explained_when_readable() {
+        assert_eq!(
+            ParkingFailure::from_response(r#"{"ErrorMessage": "Unknown parking area"}"#)
+                .map(|failure| failure.message),
+            Some("Unknown parking area".to_string())
+        );
+        assert_eq!(ParkingFailure::from_response("Bad Gateway"), None);
+    }
+}
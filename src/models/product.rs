@@ -1,7 +1,8 @@
 use crate::utils::money::Money;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Product {
     #[serde(skip)]
     pub id: String,
@@ -9,3 +10,12 @@ pub struct Product {
     pub name: String,
     pub price: Money,
 }
+
+impl Product {
+    pub fn cmp_by_id(&self, other: &Product) -> Ordering {
+        match (self.id.parse::<i32>(), other.id.parse::<i32>()) {
+            (Ok(id_a), Ok(id_b)) => id_a.cmp(&id_b),
+            _ => self.id.cmp(&other.id),
+        }
+    }
+}
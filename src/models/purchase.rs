@@ -0,0 +1,96 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+
+use crate::utils::money::Money;
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PurchaseResponse {
+    #[serde(default, deserialize_with = "lenient")]
+    pub values: Option<PurchaseValues>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct PurchaseValues {
+    #[serde(default, deserialize_with = "lenient")]
+    pub cost: Option<Money>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub member_balance: Option<Money>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub member_has_low_balance: Option<bool>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub promille: Option<f64>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub is_ballmer_peaking: Option<bool>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub bp_minutes: Option<i64>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub bp_seconds: Option<i64>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub caffeine: Option<f64>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub cups: Option<u32>,
+    #[serde(default, deserialize_with = "lenient")]
+    pub is_coffee_master: Option<bool>,
+}
+
+impl PurchaseResponse {
+    pub fn cost(&self) -> Option<Money> {
+        self.values.as_ref().and_then(|values| values.cost)
+    }
+
+    pub fn member_balance(&self) -> Option<Money> {
+        self.values
+            .as_ref()
+            .and_then(|values| values.member_balance)
+    }
+
+    pub fn highlights(&self) -> Vec<String> {
+        let Some(values) = &self.values else {
+            return Vec::new();
+        };
+
+        let mut highlights = Vec::new();
+
+        if let Some(promille) = values.promille.filter(|p| *p > 0.0) {
+            highlights.push(format!("Promille: {promille:.2}‰"));
+        }
+
+        if values.is_ballmer_peaking == Some(true) {
+            highlights.push(format!(
+                "Ballmer peak! {}m {}s left",
+                values.bp_minutes.unwrap_or(0),
+                values.bp_seconds.unwrap_or(0)
+            ));
+        }
+
+        if let Some(caffeine) = values.caffeine.filter(|c| *c > 0.0) {
+            highlights.push(format!("Caffeine: {caffeine:.0} mg"));
+        }
+
+        if let Some(cups) = values.cups.filter(|c| *c > 0) {
+            highlights.push(format!(
+                "{cups} cup{} today",
+                if cups == 1 { "" } else { "s" }
+            ));
+        }
+
+        if values.is_coffee_master == Some(true) {
+            highlights.push("Coffee master".to_string());
+        }
+
+        if values.member_has_low_balance == Some(true) {
+            highlights.push("Low balance".to_string());
+        }
+
+        highlights
+    }
+}
+
+fn lenient<'de, D, T>(deserializer: D) -> std::result::Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    let value = serde_json::Value::deserialize(deserializer)?;
+    Ok(T::deserialize(value).ok())
+}
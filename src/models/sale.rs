@@ -1,7 +1,7 @@
 use crate::utils::money::Money;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Deserialize, Serialize, Clone)]
 pub struct Sale {
     pub timestamp: String,
     pub product: String,
@@ -9,9 +9,21 @@ pub struct Sale {
 }
 
 impl Sale {
+    pub fn local_time(&self) -> Option<chrono::DateTime<chrono::Local>> {
+        chrono::DateTime::parse_from_rfc3339(&self.timestamp)
+            .ok()
+            .map(|dt| dt.with_timezone(&chrono::Local))
+    }
+
     pub fn formatted_timestamp(&self) -> String {
         chrono::DateTime::parse_from_rfc3339(&self.timestamp)
             .map(|dt| dt.format("%d/%m/%Y %H:%M").to_string())
             .unwrap_or_else(|_| "Invalid date".to_string())
     }
+
+    pub fn iso_timestamp(&self) -> String {
+        chrono::DateTime::parse_from_rfc3339(&self.timestamp)
+            .map(|dt| dt.to_rfc3339())
+            .unwrap_or_else(|_| self.timestamp.clone())
+    }
 }
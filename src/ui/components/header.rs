@@ -2,12 +2,21 @@ use chrono::{Datelike, Local, Timelike};
 use ratatui::{
     layout::Rect,
     style::{Color, Modifier, Style},
-    text::Span,
+    text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
 };
 
-pub fn render_title(f: &mut Frame, area: Rect) {
+use crate::models::parking::ParkingRegistration;
+use crate::utils::formatters::format_countdown;
+
+pub fn render_title(
+    f: &mut Frame,
+    area: Rect,
+    demo: bool,
+    dry_run: bool,
+    active_parking: Option<&ParkingRegistration>,
+) {
     let now = Local::now();
 
     let datetime_str = format!(
@@ -20,12 +29,20 @@ pub fn render_title(f: &mut Frame, area: Rect) {
         now.second()
     );
 
+    let mut title = "Stregsystemet-TUI v1.0".to_string();
+    if demo {
+        title.push_str(" [DEMO]");
+    }
+    if dry_run {
+        title.push_str(" [DRY RUN]");
+    }
+
     let title_block = Block::default()
         .borders(Borders::ALL)
         .title(format!(
             " {} ",
             Span::styled(
-                "Stregsystemet-TUI v1.0",
+                title,
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD)
@@ -43,7 +60,29 @@ pub fn render_title(f: &mut Frame, area: Rect) {
             bottom: 0,
         });
 
-    let title_text = Paragraph::new(datetime_str)
+    let mut spans = vec![Span::raw(datetime_str)];
+
+    if let Some((parking, remaining)) = active_parking
+        .and_then(|parking| parking.remaining(now).map(|remaining| (parking, remaining)))
+    {
+        let color = if remaining.num_minutes() < 30 {
+            Color::Yellow
+        } else {
+            Color::Green
+        };
+
+        spans.push(Span::raw("  |  "));
+        spans.push(Span::styled(
+            format!(
+                "Parking {}: {} left",
+                parking.plate,
+                format_countdown(remaining)
+            ),
+            Style::default().fg(color),
+        ));
+    }
+
+    let title_text = Paragraph::new(Line::from(spans))
         .style(Style::default().fg(Color::Gray))
         .block(title_block);
 
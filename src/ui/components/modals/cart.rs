@@ -0,0 +1,221 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+
+use super::{render_modal_frame, ModalConfig};
+use crate::app::state::CartLine;
+use crate::models::product::Product;
+use crate::ui::components::helpers::layout::truncate_with_ellipsis;
+use crate::utils::money::Money;
+
+pub struct CartViewState<'a> {
+    pub lines: Vec<(&'a CartLine, Option<&'a Product>)>,
+    pub selected_index: usize,
+    pub total: Money,
+    pub current_balance: Option<Money>,
+    pub error: Option<&'a String>,
+    pub success: bool,
+    pub pending: Option<&'a str>,
+}
+
+pub fn render_cart_modal(f: &mut Frame, area: Rect, view_state: &CartViewState) {
+    let (title, border_color) = if view_state.success {
+        ("Purchase Successful!".to_string(), Color::Green)
+    } else if view_state.error.is_some() {
+        ("Purchase Failed".to_string(), Color::Red)
+    } else {
+        ("Cart".to_string(), Color::Gray)
+    };
+
+    let list_height = view_state.lines.len().clamp(3, 12) as u16;
+
+    let config = ModalConfig {
+        title,
+        min_width: 90,
+        min_height: list_height + 10,
+        border_color,
+        bg_color: Color::Black,
+    };
+
+    render_modal_frame(f, area, &config, |f, inner_area| {
+        render_cart_content(f, inner_area, view_state, list_height);
+    });
+}
+
+fn render_cart_content(f: &mut Frame, area: Rect, view_state: &CartViewState, list_height: u16) {
+    let content_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .vertical_margin(1)
+        .horizontal_margin(2)
+        .constraints(
+            [
+                Constraint::Length(list_height + 2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    render_cart_lines(f, content_chunks[0], view_state);
+
+    let total_text = Text::from(vec![Line::from(vec![
+        Span::styled(
+            " Total: ",
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            view_state.total.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ])]);
+    f.render_widget(Paragraph::new(total_text), content_chunks[1]);
+
+    if let Some(bal) = view_state.current_balance {
+        let sufficient = bal >= view_state.total;
+        let balance_color = if sufficient { Color::Green } else { Color::Red };
+
+        let balance_text = Text::from(vec![Line::from(vec![
+            Span::styled(
+                " Your balance: ",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(bal.to_string(), Style::default().fg(balance_color)),
+            if !sufficient {
+                Span::styled(
+                    "  (Insufficient for this purchase)",
+                    Style::default().fg(Color::Red),
+                )
+            } else {
+                Span::raw("")
+            },
+        ])]);
+        f.render_widget(Paragraph::new(balance_text), content_chunks[2]);
+    }
+
+    let (message, color) = if let Some(spinner) = view_state.pending {
+        (format!("{spinner} Processing purchase…"), Color::Yellow)
+    } else if view_state.success {
+        ("Purchase completed successfully!".to_string(), Color::Green)
+    } else if let Some(err) = view_state.error {
+        (err.clone(), Color::Red)
+    } else if view_state.lines.is_empty() {
+        (
+            "Your cart is empty. Press 'a' on a product to add it".to_string(),
+            Color::Gray,
+        )
+    } else {
+        (
+            "Buy everything in the cart as one purchase?".to_string(),
+            Color::White,
+        )
+    };
+
+    f.render_widget(
+        Paragraph::new(message)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        content_chunks[4],
+    );
+
+    let help_text = if view_state.pending.is_some() {
+        "'esc': Cancel"
+    } else if view_state.success || view_state.error.is_some() {
+        "Press any key to close"
+    } else {
+        "'j/k': Select | '+/-': Quantity | 'd': Remove | 'y': Buy all | 'esc': Close"
+    };
+
+    let help_paragraph = Paragraph::new(help_text)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(help_paragraph, content_chunks[5]);
+}
+
+fn render_cart_lines(f: &mut Frame, area: Rect, view_state: &CartViewState) {
+    let block = Block::default().borders(Borders::ALL).title(" Items ");
+
+    if view_state.lines.is_empty() {
+        f.render_widget(block, area);
+        return;
+    }
+
+    let content_width = area.width.saturating_sub(6) as usize;
+    let id_width = view_state
+        .lines
+        .iter()
+        .map(|(line, _)| line.product_id.len() + 1)
+        .max()
+        .unwrap_or(0);
+    let amount_width = 30;
+    let name_width = content_width.saturating_sub(id_width + amount_width + 2);
+
+    let items: Vec<ListItem> = view_state
+        .lines
+        .iter()
+        .map(|(line, product)| {
+            let id_formatted = format!("{:<id_width$}", format!("{}:", line.product_id));
+
+            let (name, amount) = match product {
+                Some(product) => (
+                    product.name.clone(),
+                    format!(
+                        "{} × {} = {}",
+                        line.quantity,
+                        product.price,
+                        product.price * line.quantity
+                    ),
+                ),
+                None => (
+                    "Unavailable product".to_string(),
+                    format!("{} × ?", line.quantity),
+                ),
+            };
+
+            let name = truncate_with_ellipsis(&name, name_width as u16);
+
+            ListItem::new(Line::from(vec![
+                Span::styled(id_formatted, Style::default().fg(Color::Blue)),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{name:<name_width$}"),
+                    Style::default().fg(if product.is_some() {
+                        Color::White
+                    } else {
+                        Color::Red
+                    }),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{amount:>amount_width$}"),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::Gray)
+            .fg(Color::Black)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(view_state.selected_index));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
@@ -0,0 +1,60 @@
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+
+use crate::api::dry_run::DryRunRequest;
+
+pub fn render_dry_run_panel(f: &mut Frame, area: Rect, request: &DryRunRequest) {
+    let request_line = format!("POST {}", request.url);
+    let body = request.body.to_string();
+
+    let width = area.width.saturating_sub(4).min(110);
+    let text_width = width.saturating_sub(4).max(1) as usize;
+    let wrapped_height = |text: &str| text.chars().count().div_ceil(text_width).max(1) as u16;
+
+    let height = (wrapped_height(&request_line) + wrapped_height(&body) + 3)
+        .min(area.height.saturating_sub(2) / 2);
+
+    let panel_area = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + area.height.saturating_sub(height + 1),
+        width,
+        height,
+    };
+
+    let lines = vec![
+        Line::from(Span::styled(
+            "Dry run, confirming will not send this request:",
+            Style::default().fg(Color::Gray),
+        )),
+        Line::from(Span::styled(
+            request_line,
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(body),
+    ];
+
+    let block = Block::default()
+        .title(Span::styled(
+            " Dry Run ",
+            Style::default().add_modifier(Modifier::BOLD),
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black))
+        .padding(Padding::horizontal(1));
+
+    f.render_widget(Clear, panel_area);
+    f.render_widget(
+        Paragraph::new(lines)
+            .block(block)
+            .wrap(Wrap { trim: false }),
+        panel_area,
+    );
+}
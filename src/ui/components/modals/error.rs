@@ -14,6 +14,7 @@ pub fn render_error_modal(
     area: Rect,
     message: &str,
     title: Option<&str>,
+    success: bool,
     input_mode: &InputMode,
 ) {
     if *input_mode != InputMode::ErrorModal {
@@ -42,16 +43,16 @@ pub fn render_error_modal(
         title: title.unwrap_or("Error").to_string(),
         min_width: width,
         min_height: height,
-        border_color: Color::Red,
+        border_color: if success { Color::Green } else { Color::Red },
         bg_color: Color::Black,
     };
 
     render_modal_frame(f, area, &config, |f, inner_area| {
-        render_error_content(f, inner_area, message);
+        render_error_content(f, inner_area, message, success);
     });
 }
 
-fn render_error_content(f: &mut Frame, area: Rect, message: &str) {
+fn render_error_content(f: &mut Frame, area: Rect, message: &str, success: bool) {
     let content_chunks = Layout::default()
         .direction(Direction::Vertical)
         .vertical_margin(1)
@@ -78,8 +79,18 @@ fn render_error_content(f: &mut Frame, area: Rect, message: &str) {
         padded_chunks[0]
     };
 
-    let title_text = Paragraph::new("An error has occurred")
-        .style(Style::default().fg(Color::Red).add_modifier(Modifier::BOLD))
+    let (heading, heading_color) = if success {
+        ("Success", Color::Green)
+    } else {
+        ("An error has occurred", Color::Red)
+    };
+
+    let title_text = Paragraph::new(heading)
+        .style(
+            Style::default()
+                .fg(heading_color)
+                .add_modifier(Modifier::BOLD),
+        )
         .alignment(Alignment::Center);
     f.render_widget(title_text, content_chunks[0]);
 
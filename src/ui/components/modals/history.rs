@@ -0,0 +1,227 @@
+use chrono::NaiveDate;
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+    Frame,
+};
+
+use super::{render_modal_frame, ModalConfig};
+use crate::app::history::HistoryDay;
+use crate::app::state::{HistoryField, HistoryModalState};
+use crate::utils::formatters::{sanitize_html, truncate_text};
+use crate::utils::money::Money;
+
+pub struct HistoryViewState<'a> {
+    pub history: &'a HistoryModalState,
+    pub days: &'a [HistoryDay<'a>],
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    pub invalid_date: bool,
+}
+
+pub fn render_history_modal(f: &mut Frame, area: Rect, view_state: &HistoryViewState) {
+    let config = ModalConfig {
+        title: "Purchase History".to_string(),
+        min_width: area.width,
+        min_height: area.height,
+        border_color: Color::Gray,
+        bg_color: Color::Black,
+    };
+
+    render_modal_frame(f, area, &config, |f, inner_area| {
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Min(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .split(inner_area);
+
+        render_history_filters(f, content_chunks[0], view_state);
+        render_history_rows(f, content_chunks[2], view_state);
+        render_history_summary(f, content_chunks[4], view_state.days);
+
+        let help = if view_state.history.editing.is_some() {
+            "'tab': Next filter | 'enter' or 'esc': Done"
+        } else {
+            "'j/k': Scroll | 'ctrl+d/u': Page | 'g/G': Top/Bottom | '/': Product | 'f/t': From/To | 'x': Clear | 'esc': Close"
+        };
+
+        let help_text = Paragraph::new(help)
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        f.render_widget(help_text, content_chunks[5]);
+    });
+}
+
+fn render_history_filters(f: &mut Frame, area: Rect, view_state: &HistoryViewState) {
+    let history = view_state.history;
+
+    let mut spans = Vec::new();
+    spans.extend(filter_spans(
+        "Product",
+        &history.product,
+        history.editing == Some(HistoryField::Product),
+        true,
+    ));
+    spans.push(Span::raw("   "));
+    spans.extend(filter_spans(
+        "From",
+        &history.from,
+        history.editing == Some(HistoryField::From),
+        history.from.trim().is_empty() || view_state.from.is_some(),
+    ));
+    spans.push(Span::raw("   "));
+    spans.extend(filter_spans(
+        "To",
+        &history.to,
+        history.editing == Some(HistoryField::To),
+        history.to.trim().is_empty() || view_state.to.is_some(),
+    ));
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn filter_spans(label: &str, value: &str, editing: bool, valid: bool) -> Vec<Span<'static>> {
+    let label_style = Style::default()
+        .fg(Color::Gray)
+        .add_modifier(Modifier::BOLD);
+
+    let value_style = if !valid {
+        Style::default().fg(Color::Red)
+    } else if editing {
+        Style::default().fg(Color::Yellow)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let value = match (value.is_empty(), editing) {
+        (_, true) => format!("{value}_"),
+        (true, false) => "any".to_string(),
+        (false, false) if !valid => format!("{value} (invalid date)"),
+        (false, false) => value.to_string(),
+    };
+
+    vec![
+        Span::styled(format!("{label}: "), label_style),
+        Span::styled(value, value_style),
+    ]
+}
+
+fn render_history_rows(f: &mut Frame, area: Rect, view_state: &HistoryViewState) {
+    if view_state.days.is_empty() {
+        let message = if view_state.invalid_date {
+            "Invalid date, use e.g. 2026-10-17, 17/10/2026 or 17/10"
+        } else {
+            "No purchases match the filters"
+        };
+
+        f.render_widget(
+            Paragraph::new(message)
+                .style(Style::default().fg(Color::DarkGray))
+                .alignment(Alignment::Center),
+            area,
+        );
+        return;
+    }
+
+    let width = area.width as usize;
+    let price_width = 14;
+    let name_width = width.saturating_sub(price_width + 10);
+
+    let mut lines = Vec::new();
+
+    for day in view_state.days {
+        let summary = format!(
+            "{} purchase{}  {}",
+            day.sales.len(),
+            if day.sales.len() == 1 { "" } else { "s" },
+            day.total
+        );
+        let date = day.date.format(" %a %d/%m/%Y").to_string();
+        let padding = width.saturating_sub(date.chars().count() + summary.chars().count());
+
+        lines.push(Line::from(vec![
+            Span::styled(
+                date,
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(" ".repeat(padding)),
+            Span::styled(
+                summary,
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+
+        for (time, sale) in &day.sales {
+            let product = truncate_text(&sanitize_html(&sale.product), name_width);
+
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("   {}  ", time.format("%H:%M")),
+                    Style::default().fg(Color::Gray),
+                ),
+                Span::raw(format!("{product:<name_width$}")),
+                Span::styled(
+                    format!("{:>price_width$}", sale.price.to_string()),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]));
+        }
+    }
+
+    let visible = area.height as usize;
+    let scroll = view_state
+        .history
+        .scroll
+        .min(lines.len().saturating_sub(visible));
+
+    let lines: Vec<Line> = lines.into_iter().skip(scroll).take(visible).collect();
+    f.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_history_summary(f: &mut Frame, area: Rect, days: &[HistoryDay]) {
+    let count: usize = days.iter().map(|day| day.sales.len()).sum();
+    let total: Money = days.iter().map(|day| day.total).sum();
+
+    let summary = Line::from(vec![
+        Span::styled(
+            " Total: ",
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            total.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(
+                "  ({count} purchase{} on {} day{})",
+                if count == 1 { "" } else { "s" },
+                days.len(),
+                if days.len() == 1 { "" } else { "s" },
+            ),
+            Style::default().fg(Color::Gray),
+        ),
+    ]);
+
+    f.render_widget(Paragraph::new(summary), area);
+}
@@ -7,10 +7,16 @@ use ratatui::{
     Frame,
 };
 
+pub mod cart;
+pub mod dry_run;
 pub mod error;
+pub mod history;
 pub mod parking;
+pub mod parking_log;
 pub mod purchase;
+pub mod quick_buy;
 pub mod search;
+pub mod stats;
 pub mod terminal_size;
 pub mod username;
 
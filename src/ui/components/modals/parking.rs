@@ -1,193 +1,309 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Style},
-    widgets::Paragraph,
+    style::{Color, Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, List, ListItem, ListState, Padding, Paragraph},
     Frame,
 };
 
 use super::{render_modal_frame, ModalConfig};
-use crate::app::state::{InputMode, ParkingModalState};
+use crate::app::state::{InputMode, ParkingEditor, ParkingModalState};
+use crate::models::parking::{ParkingProfile, ParkingRegistration, Vehicle};
+use crate::models::permit::ParkingPermit;
+
+pub struct ParkingViewState<'a> {
+    pub parking: &'a ParkingModalState,
+    pub profile: &'a ParkingProfile,
+    pub profile_count: usize,
+    pub phone_numbers: &'a [String],
+    pub vehicles: &'a [Vehicle],
+    pub active: Option<&'a ParkingRegistration>,
+    pub spinner: &'a str,
+}
 
 pub fn render_parking_modal(
     f: &mut Frame,
     area: Rect,
-    parking_state: &ParkingModalState,
+    view_state: &ParkingViewState,
     input_mode: &InputMode,
 ) {
-    if parking_state.confirming {
-        render_parking_confirmation_modal(f, area, parking_state);
-    } else {
-        render_parking_input_modal(f, area, parking_state, input_mode);
+    if view_state.parking.confirming {
+        render_parking_confirmation_modal(f, area, view_state);
+        return;
+    }
+
+    render_parking_input_modal(f, area, view_state);
+
+    if let Some(ref editor) = view_state.parking.editor {
+        render_parking_editor(f, area, editor, input_mode);
     }
 }
 
-fn render_parking_input_modal(
-    f: &mut Frame,
-    area: Rect,
-    parking_state: &ParkingModalState,
-    input_mode: &InputMode,
-) {
+fn render_parking_input_modal(f: &mut Frame, area: Rect, view_state: &ParkingViewState) {
+    let phone_height = view_state.phone_numbers.len().clamp(1, 4) as u16;
+    let vehicle_height = view_state.vehicles.len().clamp(1, 5) as u16;
+
     let config = ModalConfig {
         title: "Parking Registration".to_string(),
-        min_width: 60,
-        min_height: 14,
+        min_width: 82,
+        min_height: phone_height + vehicle_height + 13,
         border_color: Color::Gray,
         bg_color: Color::Black,
     };
 
     render_modal_frame(f, area, &config, |f, inner_area| {
-        render_parking_input_content(f, inner_area, parking_state, input_mode);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .vertical_margin(1)
+            .horizontal_margin(3)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Length(phone_height + 2),
+                    Constraint::Length(vehicle_height + 2),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Min(0),
+                ]
+                .as_ref(),
+            )
+            .split(inner_area);
+
+        let profile = view_state.profile;
+        let zone_text = if view_state.profile_count > 1 {
+            format!(" Zone: ‹ {} ›", profile.summary())
+        } else {
+            format!(" Zone: {}", profile.summary())
+        };
+        f.render_widget(
+            Paragraph::new(zone_text).style(Style::default().fg(Color::Cyan)),
+            chunks[0],
+        );
+
+        let phone_items: Vec<String> = view_state
+            .phone_numbers
+            .iter()
+            .map(|number| format!("+{} {number}", profile.phone_prefix))
+            .collect();
+        render_parking_list(
+            f,
+            chunks[2],
+            " Phone numbers ",
+            &phone_items,
+            view_state.parking.phone_index,
+            view_state.parking.current_field == 0,
+        );
+
+        let vehicle_items: Vec<String> = view_state.vehicles.iter().map(Vehicle::label).collect();
+        render_parking_list(
+            f,
+            chunks[3],
+            " Vehicles ",
+            &vehicle_items,
+            view_state.parking.vehicle_index,
+            view_state.parking.current_field == 1,
+        );
+
+        let list_help = "'tab': Switch list | 'j/k': Select | 'a': Add | 'e': Edit | 'd': Delete";
+        let action_help = if view_state.profile_count > 1 {
+            "'←/→': Zone | 'enter': Next | 'esc': Cancel"
+        } else {
+            "'enter': Next | 'esc': Cancel"
+        };
+
+        for (text, area) in [(list_help, chunks[5]), (action_help, chunks[6])] {
+            f.render_widget(
+                Paragraph::new(text)
+                    .style(Style::default().fg(Color::Gray))
+                    .alignment(Alignment::Center),
+                area,
+            );
+        }
+    });
+}
+
+fn render_parking_list(
+    f: &mut Frame,
+    area: Rect,
+    title: &str,
+    items: &[String],
+    selected: usize,
+    focused: bool,
+) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .border_style(Style::default().fg(if focused { Color::Blue } else { Color::Gray }));
+
+    if items.is_empty() {
+        f.render_widget(
+            Paragraph::new(" Nothing saved yet, press 'a' to add")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let list = List::new(
+        items
+            .iter()
+            .map(|item| ListItem::new(Line::from(format!(" {item}"))))
+            .collect::<Vec<_>>(),
+    )
+    .block(block)
+    .highlight_style(if focused {
+        Style::default()
+            .bg(Color::Gray)
+            .fg(Color::Black)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::Yellow)
     });
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(selected));
+    f.render_stateful_widget(list, area, &mut list_state);
 }
 
-fn render_parking_input_content(
+fn render_parking_editor(
     f: &mut Frame,
     area: Rect,
-    parking_state: &ParkingModalState,
+    editor: &ParkingEditor,
     input_mode: &InputMode,
 ) {
-    let content_chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .vertical_margin(1)
-        .constraints(
-            [
-                Constraint::Length(1),
-                Constraint::Length(3),
-                Constraint::Length(1),
-                Constraint::Length(3),
-                Constraint::Length(1),
-                Constraint::Length(1),
-                Constraint::Min(0),
-            ]
-            .as_ref(),
-        )
-        .split(area);
+    let (title, fields, current) = match editor {
+        ParkingEditor::Phone { index, number } => (
+            if index.is_some() {
+                "Edit Phone Number"
+            } else {
+                "Add Phone Number"
+            },
+            vec![(" Phone number (without country code):", number.as_str())],
+            0,
+        ),
+        ParkingEditor::Vehicle {
+            index,
+            field,
+            plate,
+            country,
+            nickname,
+        } => (
+            if index.is_some() {
+                "Edit Vehicle"
+            } else {
+                "Add Vehicle"
+            },
+            vec![
+                (" License plate:", plate.as_str()),
+                (" Country (e.g. DK, DE, SE):", country.as_str()),
+                (" Nickname (optional):", nickname.as_str()),
+            ],
+            *field,
+        ),
+    };
+
+    let config = ModalConfig {
+        title: title.to_string(),
+        min_width: 56,
+        min_height: fields.len() as u16 * 4 + 7,
+        border_color: Color::Blue,
+        bg_color: Color::Black,
+    };
 
-    let phone_label_area = Layout::default()
-        .direction(Direction::Horizontal)
-        .horizontal_margin(3)
-        .constraints([Constraint::Min(0)])
-        .split(content_chunks[0])[0];
+    render_modal_frame(f, area, &config, |f, inner_area| {
+        let mut constraints: Vec<Constraint> = fields
+            .iter()
+            .flat_map(|_| [Constraint::Length(1), Constraint::Length(3)])
+            .collect();
+        constraints.push(Constraint::Length(1));
+        constraints.push(Constraint::Length(1));
+        constraints.push(Constraint::Min(0));
 
-    let phone_label = Paragraph::new(" Phone number (8 digits):")
-        .style(Style::default().fg(if parking_state.current_field == 0 {
-            Color::Yellow
-        } else {
-            Color::White
-        }))
-        .alignment(Alignment::Left);
-    f.render_widget(phone_label, phone_label_area);
-
-    let phone_input_area = Layout::default()
-        .direction(Direction::Horizontal)
-        .horizontal_margin(3)
-        .constraints([Constraint::Min(0)])
-        .split(content_chunks[1])[0];
-
-    let phone_input = Paragraph::new(parking_state.phone_input.as_str())
-        .style(match input_mode {
-            InputMode::ParkingModal if parking_state.current_field == 0 => {
-                Style::default().fg(Color::Yellow)
-            }
-            _ => Style::default(),
-        })
-        .block(
-            ratatui::widgets::Block::default()
-                .borders(ratatui::widgets::Borders::ALL)
-                .border_style(Style::default().fg(if parking_state.current_field == 0 {
-                    Color::Blue
-                } else {
-                    Color::Gray
-                }))
-                .padding(ratatui::widgets::Padding {
-                    left: 1,
-                    right: 1,
-                    top: 0,
-                    bottom: 0,
-                }),
-        );
-    f.render_widget(phone_input, phone_input_area);
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .constraints(constraints)
+            .split(inner_area);
 
-    let plate_label_area = Layout::default()
-        .direction(Direction::Horizontal)
-        .horizontal_margin(3)
-        .constraints([Constraint::Min(0)])
-        .split(content_chunks[2])[0];
+        for (i, (label, value)) in fields.iter().enumerate() {
+            let active = i == current;
 
-    let plate_label = Paragraph::new(" License plate:")
-        .style(Style::default().fg(if parking_state.current_field == 1 {
-            Color::Yellow
-        } else {
-            Color::White
-        }))
-        .alignment(Alignment::Left);
-    f.render_widget(plate_label, plate_label_area);
-
-    let plate_input_area = Layout::default()
-        .direction(Direction::Horizontal)
-        .horizontal_margin(3)
-        .constraints([Constraint::Min(0)])
-        .split(content_chunks[3])[0];
-
-    let plate_input = Paragraph::new(parking_state.license_plate_input.as_str())
-        .style(match input_mode {
-            InputMode::ParkingModal if parking_state.current_field == 1 => {
-                Style::default().fg(Color::Yellow)
-            }
-            _ => Style::default(),
-        })
-        .block(
-            ratatui::widgets::Block::default()
-                .borders(ratatui::widgets::Borders::ALL)
-                .border_style(Style::default().fg(if parking_state.current_field == 1 {
-                    Color::Blue
+            f.render_widget(
+                Paragraph::new(*label).style(Style::default().fg(if active {
+                    Color::Yellow
                 } else {
-                    Color::Gray
-                }))
-                .padding(ratatui::widgets::Padding {
-                    left: 1,
-                    right: 1,
-                    top: 0,
-                    bottom: 0,
-                }),
-        );
-    f.render_widget(plate_input, plate_input_area);
+                    Color::White
+                })),
+                chunks[i * 2],
+            );
 
-    let help_text = Paragraph::new("'tab': Switch field | 'enter': Next | 'esc': Cancel")
-        .style(Style::default().fg(Color::Gray))
-        .alignment(Alignment::Center);
-    f.render_widget(help_text, content_chunks[5]);
+            let input_area = chunks[i * 2 + 1];
+            f.render_widget(
+                Paragraph::new(*value)
+                    .style(if active {
+                        Style::default().fg(Color::Yellow)
+                    } else {
+                        Style::default()
+                    })
+                    .block(
+                        Block::default()
+                            .borders(Borders::ALL)
+                            .border_style(Style::default().fg(if active {
+                                Color::Blue
+                            } else {
+                                Color::Gray
+                            }))
+                            .padding(Padding::horizontal(1)),
+                    ),
+                input_area,
+            );
+
+            if active && *input_mode == InputMode::ParkingModal {
+                let cursor_x = input_area.x
+                    + value
+                        .chars()
+                        .count()
+                        .min((input_area.width as usize).saturating_sub(5))
+                        as u16
+                    + 2;
+                f.set_cursor_position((cursor_x, input_area.y + 1));
+            }
+        }
 
-    if let InputMode::ParkingModal = input_mode {
-        let (cursor_area, text) = if parking_state.current_field == 0 {
-            (phone_input_area, &parking_state.phone_input)
+        let help = if fields.len() > 1 {
+            "'tab': Next field | 'enter': Save | 'esc': Cancel"
         } else {
-            (plate_input_area, &parking_state.license_plate_input)
+            "'enter': Save | 'esc': Cancel"
         };
-
-        let cursor_x = cursor_area.x
-            + text
-                .len()
-                .min((cursor_area.width as usize).saturating_sub(5)) as u16
-            + 2;
-
-        f.set_cursor_position((cursor_x, cursor_area.y + 1));
-    }
+        f.render_widget(
+            Paragraph::new(help)
+                .style(Style::default().fg(Color::Gray))
+                .alignment(Alignment::Center),
+            chunks[fields.len() * 2 + 1],
+        );
+    });
 }
 
-fn render_parking_confirmation_modal(f: &mut Frame, area: Rect, parking_state: &ParkingModalState) {
-    if parking_state.success || parking_state.error.is_some() {
-        render_parking_result_modal(f, area, parking_state);
+fn render_parking_confirmation_modal(f: &mut Frame, area: Rect, view_state: &ParkingViewState) {
+    if view_state.parking.success || view_state.parking.error.is_some() {
+        render_parking_result_modal(f, area, view_state.parking);
     } else {
-        render_parking_confirm_modal(f, area, parking_state);
+        render_parking_confirm_modal(f, area, view_state);
     }
 }
 
-fn render_parking_confirm_modal(f: &mut Frame, area: Rect, parking_state: &ParkingModalState) {
+fn render_parking_confirm_modal(f: &mut Frame, area: Rect, view_state: &ParkingViewState) {
+    let parking_state = view_state.parking;
+    let profile = view_state.profile;
+
     let config = ModalConfig {
         title: "Confirm Parking".to_string(),
         min_width: 50,
-        min_height: 9,
+        min_height: 10,
         border_color: Color::Yellow,
         bg_color: Color::Black,
     };
@@ -203,38 +319,84 @@ fn render_parking_confirm_modal(f: &mut Frame, area: Rect, parking_state: &Parki
                     Constraint::Length(1),
                     Constraint::Length(1),
                     Constraint::Length(1),
+                    Constraint::Length(1),
                     Constraint::Min(0),
                 ]
                 .as_ref(),
             )
             .split(inner_area);
 
-        let phone_text = Paragraph::new(format!(" Phone: +45 {}", parking_state.phone_input))
+        let zone_text = Paragraph::new(format!(" Zone: {}", profile.name))
             .style(Style::default().fg(Color::White))
             .alignment(Alignment::Left);
-        f.render_widget(phone_text, content_chunks[0]);
+        f.render_widget(zone_text, content_chunks[0]);
+
+        let phone_text = Paragraph::new(format!(
+            " Phone: +{} {}",
+            profile.phone_prefix,
+            view_state
+                .phone_numbers
+                .get(parking_state.phone_index)
+                .map_or("", |number| number.as_str())
+        ))
+        .style(Style::default().fg(Color::White))
+        .alignment(Alignment::Left);
+        f.render_widget(phone_text, content_chunks[1]);
 
         let plate_text = Paragraph::new(format!(
-            " License plate: {}",
-            parking_state.license_plate_input
+            " Vehicle: {}",
+            view_state
+                .vehicles
+                .get(parking_state.vehicle_index)
+                .map(Vehicle::label)
+                .unwrap_or_default()
         ))
         .style(Style::default().fg(Color::White))
         .alignment(Alignment::Left);
-        f.render_widget(plate_text, content_chunks[1]);
+        f.render_widget(plate_text, content_chunks[2]);
 
-        let confirm_text = Paragraph::new("Register parking for 10 hours?")
+        if let Some(active) = view_state.active {
+            let active_text = Paragraph::new(format!(
+                " Already parked: {} until {}",
+                active.plate,
+                active.expires_at().format("%H:%M")
+            ))
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Left);
+            f.render_widget(active_text, content_chunks[3]);
+        }
+
+        let (confirm_message, help_message) = if parking_state.pending {
+            (
+                format!("{} Registering parking…", view_state.spinner),
+                "'esc': Cancel",
+            )
+        } else {
+            (
+                format!("Register parking for {}?", profile.formatted_duration()),
+                "'y': Confirm | 'n': Cancel",
+            )
+        };
+
+        let confirm_text = Paragraph::new(confirm_message)
             .style(Style::default().fg(Color::Yellow))
             .alignment(Alignment::Center);
-        f.render_widget(confirm_text, content_chunks[3]);
+        f.render_widget(confirm_text, content_chunks[4]);
 
-        let help_text = Paragraph::new("'y': Confirm | 'n': Cancel")
+        let help_text = Paragraph::new(help_message)
             .style(Style::default().fg(Color::Gray))
             .alignment(Alignment::Center);
-        f.render_widget(help_text, content_chunks[4]);
+        f.render_widget(help_text, content_chunks[5]);
     });
 }
 
 fn render_parking_result_modal(f: &mut Frame, area: Rect, parking_state: &ParkingModalState) {
+    let permit_lines = parking_state
+        .permit
+        .as_ref()
+        .map(permit_details)
+        .unwrap_or_default();
+
     let (title, color, message) = if parking_state.success {
         (
             "Parking Registered".to_string(),
@@ -256,10 +418,12 @@ fn render_parking_result_modal(f: &mut Frame, area: Rect, parking_state: &Parkin
         )
     };
 
+    let permit_height = permit_lines.len() as u16;
+
     let config = ModalConfig {
         title,
         min_width: 60,
-        min_height: 8,
+        min_height: 8 + permit_height,
         border_color: color,
         bg_color: Color::Black,
     };
@@ -271,6 +435,7 @@ fn render_parking_result_modal(f: &mut Frame, area: Rect, parking_state: &Parkin
             .constraints(
                 [
                     Constraint::Length(2),
+                    Constraint::Length(permit_height),
                     Constraint::Length(1),
                     Constraint::Length(1),
                     Constraint::Min(0),
@@ -285,9 +450,45 @@ fn render_parking_result_modal(f: &mut Frame, area: Rect, parking_state: &Parkin
             .wrap(ratatui::widgets::Wrap { trim: true });
         f.render_widget(message_text, content_chunks[0]);
 
+        let permit_text = Paragraph::new(permit_lines)
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        f.render_widget(permit_text, content_chunks[1]);
+
         let help_text = Paragraph::new("Press any key to close")
             .style(Style::default().fg(Color::Gray))
             .alignment(Alignment::Center);
-        f.render_widget(help_text, content_chunks[2]);
+        f.render_widget(help_text, content_chunks[3]);
     });
 }
+
+fn permit_details(permit: &ParkingPermit) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+
+    if let Some(ref id) = permit.id {
+        lines.push(Line::from(format!("Permit: {id}")));
+    }
+
+    if let Some(ref zone) = permit.zone {
+        lines.push(Line::from(format!("Zone: {zone}")));
+    }
+
+    match (permit.valid_from, permit.valid_to) {
+        (Some(from), Some(to)) => lines.push(Line::from(format!(
+            "Valid: {} - {}",
+            from.format("%d/%m %H:%M"),
+            to.format("%d/%m %H:%M")
+        ))),
+        (None, Some(to)) => lines.push(Line::from(format!(
+            "Valid until: {}",
+            to.format("%d/%m %H:%M")
+        ))),
+        _ => {}
+    }
+
+    if let Some(warning) = permit.warning() {
+        lines.push(Line::styled(warning, Style::default().fg(Color::Yellow)));
+    }
+
+    lines
+}
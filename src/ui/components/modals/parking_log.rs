@@ -0,0 +1,147 @@
+use chrono::{DateTime, Local};
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    Frame,
+};
+
+use super::{render_modal_frame, ModalConfig};
+use crate::models::parking::ParkingRegistration;
+use crate::utils::formatters::{format_countdown, truncate_text};
+
+pub struct ParkingLogViewState<'a> {
+    pub entries: &'a [ParkingRegistration],
+    pub selected_index: usize,
+    pub now: DateTime<Local>,
+}
+
+pub fn render_parking_log_modal(f: &mut Frame, area: Rect, view_state: &ParkingLogViewState) {
+    let list_height = view_state.entries.len().clamp(3, 12) as u16;
+
+    let config = ModalConfig {
+        title: "Parking Log".to_string(),
+        min_width: 90,
+        min_height: list_height + 12,
+        border_color: Color::Gray,
+        bg_color: Color::Black,
+    };
+
+    render_modal_frame(f, area, &config, |f, inner_area| {
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .constraints(
+                [
+                    Constraint::Length(list_height + 2),
+                    Constraint::Min(4),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .split(inner_area);
+
+        render_parking_log_list(f, content_chunks[0], view_state);
+        render_parking_log_details(f, content_chunks[1], view_state);
+
+        let help_text = Paragraph::new("'j/k': Select | 'esc': Close")
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        f.render_widget(help_text, content_chunks[2]);
+    });
+}
+
+fn render_parking_log_list(f: &mut Frame, area: Rect, view_state: &ParkingLogViewState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Registrations ");
+
+    if view_state.entries.is_empty() {
+        f.render_widget(
+            Paragraph::new(" No parking registered yet")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let items: Vec<ListItem> = view_state
+        .entries
+        .iter()
+        .rev()
+        .map(|entry| {
+            let status = match entry.remaining(view_state.now) {
+                Some(remaining) => Span::styled(
+                    format!("{} left", format_countdown(remaining)),
+                    Style::default().fg(Color::Green),
+                ),
+                None => Span::styled("expired", Style::default().fg(Color::DarkGray)),
+            };
+
+            ListItem::new(Line::from(vec![
+                Span::raw(format!(
+                    " {}  {:<10} {:<24} ",
+                    entry.registered_at.format("%d/%m %H:%M"),
+                    entry.plate,
+                    truncate_text(&entry.profile, 24),
+                )),
+                status,
+            ]))
+        })
+        .collect();
+
+    let list = List::new(items).block(block).highlight_style(
+        Style::default()
+            .bg(Color::Gray)
+            .fg(Color::Black)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let mut list_state = ListState::default();
+    list_state.select(Some(view_state.selected_index));
+    f.render_stateful_widget(list, area, &mut list_state);
+}
+
+fn render_parking_log_details(f: &mut Frame, area: Rect, view_state: &ParkingLogViewState) {
+    let Some(entry) = view_state
+        .entries
+        .iter()
+        .rev()
+        .nth(view_state.selected_index)
+    else {
+        return;
+    };
+
+    let label = Style::default()
+        .fg(Color::Gray)
+        .add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(" Zone: ", label),
+            Span::raw(format!("{} ({})", entry.profile, entry.zone_key)),
+        ]),
+        Line::from(vec![
+            Span::styled(" Vehicle: ", label),
+            Span::raw(format!("{} ({})", entry.plate, entry.country)),
+            Span::styled("  Phone: ", label),
+            Span::raw(format!("+{}", entry.phone_number)),
+        ]),
+        Line::from(vec![
+            Span::styled(" Expires: ", label),
+            Span::raw(entry.expires_at().format("%d/%m %H:%M").to_string()),
+        ]),
+    ];
+
+    if let Some(ref id) = entry.permit.id {
+        lines.push(Line::from(vec![
+            Span::styled(" Permit: ", label),
+            Span::raw(id.clone()),
+        ]));
+    }
+
+    f.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), area);
+}
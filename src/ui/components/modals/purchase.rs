@@ -8,21 +8,24 @@ use ratatui::{
 
 use super::{render_modal_frame, ModalConfig};
 use crate::models::product::Product;
+use crate::models::purchase::PurchaseResponse;
 use crate::utils::money::Money;
 
-pub fn render_buy_modal(
-    f: &mut Frame,
-    area: Rect,
-    product_id: &str,
-    product: &Product,
-    quantity: u32,
-    current_balance: Option<Money>,
-    error: Option<&String>,
-    success: bool,
-) {
-    let (title, border_color) = if success {
+pub struct PurchaseViewState<'a> {
+    pub product_id: &'a str,
+    pub product: &'a Product,
+    pub quantity: u32,
+    pub current_balance: Option<Money>,
+    pub error: Option<&'a String>,
+    pub success: bool,
+    pub response: Option<&'a PurchaseResponse>,
+    pub pending: Option<&'a str>,
+}
+
+pub fn render_buy_modal(f: &mut Frame, area: Rect, view_state: &PurchaseViewState) {
+    let (title, border_color) = if view_state.success {
         ("Purchase Successful!".to_string(), Color::Green)
-    } else if error.is_some() {
+    } else if view_state.error.is_some() {
         ("Purchase Failed".to_string(), Color::Red)
     } else {
         ("Confirm Purchase".to_string(), Color::Gray)
@@ -37,29 +40,22 @@ pub fn render_buy_modal(
     };
 
     render_modal_frame(f, area, &config, |f, inner_area| {
-        render_purchase_content(
-            f,
-            inner_area,
-            product_id,
-            product,
-            quantity,
-            current_balance,
-            error,
-            success,
-        );
+        render_purchase_content(f, inner_area, view_state);
     });
 }
 
-fn render_purchase_content(
-    f: &mut Frame,
-    area: Rect,
-    product_id: &str,
-    product: &Product,
-    quantity: u32,
-    current_balance: Option<Money>,
-    error: Option<&String>,
-    success: bool,
-) {
+fn render_purchase_content(f: &mut Frame, area: Rect, view_state: &PurchaseViewState) {
+    let PurchaseViewState {
+        product_id,
+        product,
+        quantity,
+        current_balance,
+        error,
+        success,
+        response,
+        pending,
+    } = *view_state;
+
     let content_chunks = Layout::default()
         .direction(Direction::Vertical)
         .vertical_margin(1)
@@ -131,18 +127,29 @@ fn render_purchase_content(
         apply_padding(content_chunks[1]),
     );
 
-    let price_text = Text::from(vec![Line::from(vec![
-        Span::styled(
-            "Price: ",
-            Style::default()
-                .fg(Color::Gray)
-                .add_modifier(Modifier::BOLD),
-        ),
-        Span::styled(
-            format!("{price_formatted} × {quantity} = {total_formatted}"),
-            Style::default().fg(Color::Yellow),
-        ),
-    ])]);
+    let price_text = match response.and_then(PurchaseResponse::cost) {
+        Some(cost) if success => Text::from(vec![Line::from(vec![
+            Span::styled(
+                "Charged: ",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(cost.to_string(), Style::default().fg(Color::Yellow)),
+        ])]),
+        _ => Text::from(vec![Line::from(vec![
+            Span::styled(
+                "Price: ",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("{price_formatted} × {quantity} = {total_formatted}"),
+                Style::default().fg(Color::Yellow),
+            ),
+        ])]),
+    };
     f.render_widget(Paragraph::new(price_text), apply_padding(content_chunks[2]));
 
     let quantity_text = Text::from(vec![Line::from(vec![
@@ -160,7 +167,11 @@ fn render_purchase_content(
         ),
         Span::raw("  "),
         Span::styled(
-            "[-/+] or [←/→]: Adjust quantity",
+            if success {
+                ""
+            } else {
+                "[-/+] or [←/→]: Adjust quantity"
+            },
             Style::default().fg(Color::Gray),
         ),
     ])]);
@@ -169,7 +180,21 @@ fn render_purchase_content(
         apply_padding(content_chunks[3]),
     );
 
-    if let Some(bal) = current_balance {
+    if let (true, Some(bal)) = (success, current_balance) {
+        let balance_text = Text::from(vec![Line::from(vec![
+            Span::styled(
+                "New balance: ",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(bal.to_string(), Style::default().fg(Color::Green)),
+        ])]);
+        f.render_widget(
+            Paragraph::new(balance_text),
+            apply_padding(content_chunks[4]),
+        );
+    } else if let Some(bal) = current_balance {
         let sufficient = bal >= total_price;
         let balance_color = if sufficient { Color::Green } else { Color::Red };
 
@@ -196,7 +221,24 @@ fn render_purchase_content(
         );
     }
 
-    if success {
+    let highlights = response.map(PurchaseResponse::highlights);
+    if let (true, Some(highlights)) = (success, highlights.filter(|h| !h.is_empty())) {
+        f.render_widget(
+            Paragraph::new(highlights.join(" | ")).style(Style::default().fg(Color::Cyan)),
+            apply_padding(content_chunks[5]),
+        );
+    }
+
+    if let Some(spinner) = pending {
+        let pending_text = Text::from(vec![Line::from(vec![Span::styled(
+            format!("{spinner} Processing purchase…"),
+            Style::default().fg(Color::Yellow),
+        )])]);
+        f.render_widget(
+            Paragraph::new(pending_text).alignment(Alignment::Center),
+            content_chunks[6],
+        );
+    } else if success {
         let success_text = Text::from(vec![Line::from(vec![Span::styled(
             "Purchase completed successfully!",
             Style::default().fg(Color::Green),
@@ -241,7 +283,9 @@ fn render_purchase_content(
         );
     }
 
-    let help_text = if success || error.is_some() {
+    let help_text = if pending.is_some() {
+        "Press 'esc' to cancel"
+    } else if success || error.is_some() {
         "Press any key to close"
     } else {
         "Press 'y' to confirm or 'n' to cancel"
@@ -0,0 +1,225 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, Borders, List, ListItem, Paragraph},
+    Frame,
+};
+
+use super::{render_modal_frame, ModalConfig};
+use crate::models::product::Product;
+use crate::ui::components::helpers::layout::truncate_with_ellipsis;
+use crate::utils::money::Money;
+
+pub struct QuickBuyViewState<'a> {
+    pub input: &'a str,
+    pub lines: &'a [(Product, u32)],
+    pub parse_error: Option<&'a String>,
+    pub total: Money,
+    pub current_balance: Option<Money>,
+    pub confirming: bool,
+    pub error: Option<&'a String>,
+    pub success: bool,
+    pub pending: Option<&'a str>,
+}
+
+pub fn render_quick_buy_modal(f: &mut Frame, area: Rect, view_state: &QuickBuyViewState) {
+    let (title, border_color) = if view_state.success {
+        ("Purchase Successful!".to_string(), Color::Green)
+    } else if view_state.error.is_some() {
+        ("Purchase Failed".to_string(), Color::Red)
+    } else if view_state.confirming {
+        ("Confirm Quick Buy".to_string(), Color::Yellow)
+    } else {
+        ("Quick Buy".to_string(), Color::Gray)
+    };
+
+    let list_height = view_state.lines.len().clamp(3, 10) as u16;
+
+    let config = ModalConfig {
+        title,
+        min_width: 90,
+        min_height: list_height + 14,
+        border_color,
+        bg_color: Color::Black,
+    };
+
+    render_modal_frame(f, area, &config, |f, inner_area| {
+        render_quick_buy_content(f, inner_area, view_state, list_height);
+    });
+}
+
+fn render_quick_buy_content(
+    f: &mut Frame,
+    area: Rect,
+    view_state: &QuickBuyViewState,
+    list_height: u16,
+) {
+    let content_chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .vertical_margin(1)
+        .horizontal_margin(2)
+        .constraints(
+            [
+                Constraint::Length(3),
+                Constraint::Length(list_height + 2),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Length(1),
+                Constraint::Min(0),
+            ]
+            .as_ref(),
+        )
+        .split(area);
+
+    let input_style = if view_state.confirming {
+        Style::default().fg(Color::Gray)
+    } else {
+        Style::default().fg(Color::Yellow)
+    };
+
+    let input_widget = Paragraph::new(Line::from(vec![
+        Span::styled(":", Style::default().fg(Color::Blue)),
+        Span::styled(view_state.input, input_style),
+    ]))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(Color::Blue))
+            .title(" Buystring (e.g. '11 mate:2 32:3') "),
+    );
+    f.render_widget(input_widget, content_chunks[0]);
+
+    render_preview_lines(f, content_chunks[1], view_state);
+
+    let total_text = Text::from(vec![Line::from(vec![
+        Span::styled(
+            " Total: ",
+            Style::default()
+                .fg(Color::Gray)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            view_state.total.to_string(),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ])]);
+    f.render_widget(Paragraph::new(total_text), content_chunks[2]);
+
+    if let Some(bal) = view_state.current_balance {
+        let sufficient = bal >= view_state.total;
+        let balance_color = if sufficient { Color::Green } else { Color::Red };
+
+        let balance_text = Text::from(vec![Line::from(vec![
+            Span::styled(
+                " Your balance: ",
+                Style::default()
+                    .fg(Color::Gray)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(bal.to_string(), Style::default().fg(balance_color)),
+            if !sufficient {
+                Span::styled(
+                    "  (Insufficient for this purchase)",
+                    Style::default().fg(Color::Red),
+                )
+            } else {
+                Span::raw("")
+            },
+        ])]);
+        f.render_widget(Paragraph::new(balance_text), content_chunks[3]);
+    }
+
+    let (message, color) = if let Some(spinner) = view_state.pending {
+        (format!("{spinner} Processing purchase…"), Color::Yellow)
+    } else if view_state.success {
+        ("Purchase completed successfully!".to_string(), Color::Green)
+    } else if let Some(err) = view_state.error {
+        (err.clone(), Color::Red)
+    } else if let Some(err) = view_state.parse_error {
+        (err.clone(), Color::Red)
+    } else if view_state.confirming {
+        ("Buy these items as one purchase?".to_string(), Color::White)
+    } else {
+        (
+            "Enter product IDs, 'id:qty' or product names separated by spaces".to_string(),
+            Color::Gray,
+        )
+    };
+
+    f.render_widget(
+        Paragraph::new(message)
+            .style(Style::default().fg(color))
+            .alignment(Alignment::Center),
+        content_chunks[5],
+    );
+
+    let help_text = if view_state.pending.is_some() {
+        "'esc': Cancel"
+    } else if view_state.success || view_state.error.is_some() {
+        "Press any key to close"
+    } else if view_state.confirming {
+        "'y': Confirm | 'n': Edit | 'esc': Cancel"
+    } else {
+        "'enter': Review | 'esc': Cancel"
+    };
+
+    let help_paragraph = Paragraph::new(help_text)
+        .style(Style::default().fg(Color::Gray))
+        .alignment(Alignment::Center);
+    f.render_widget(help_paragraph, content_chunks[6]);
+}
+
+fn render_preview_lines(f: &mut Frame, area: Rect, view_state: &QuickBuyViewState) {
+    let block = Block::default().borders(Borders::ALL).title(" Preview ");
+
+    if view_state.lines.is_empty() {
+        f.render_widget(block, area);
+        return;
+    }
+
+    let content_width = area.width.saturating_sub(4) as usize;
+    let id_width = view_state
+        .lines
+        .iter()
+        .map(|(product, _)| product.id.len() + 1)
+        .max()
+        .unwrap_or(0);
+    let amount_width = 30;
+    let name_width = content_width.saturating_sub(id_width + amount_width + 2);
+
+    let items: Vec<ListItem> = view_state
+        .lines
+        .iter()
+        .map(|(product, quantity)| {
+            let id_formatted = format!("{:<id_width$}", format!("{}:", product.id));
+            let name = truncate_with_ellipsis(&product.name, name_width as u16);
+            let amount = format!(
+                "{} × {} = {}",
+                quantity,
+                product.price,
+                product.price * *quantity
+            );
+
+            ListItem::new(Line::from(vec![
+                Span::styled(id_formatted, Style::default().fg(Color::Blue)),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{name:<name_width$}"),
+                    Style::default().fg(Color::White),
+                ),
+                Span::raw(" "),
+                Span::styled(
+                    format!("{amount:>amount_width$}"),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ]))
+        })
+        .collect();
+
+    f.render_widget(List::new(items).block(block), area);
+}
@@ -0,0 +1,198 @@
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Bar, BarChart, BarGroup, Block, Borders, Paragraph, Sparkline},
+    Frame,
+};
+
+use super::{render_modal_frame, ModalConfig};
+use crate::app::stats::{SpendingStats, StatsPeriod};
+use crate::utils::formatters::truncate_text;
+
+pub struct StatsViewState<'a> {
+    pub stats: &'a SpendingStats,
+    pub period: StatsPeriod,
+}
+
+pub fn render_stats_modal(f: &mut Frame, area: Rect, view_state: &StatsViewState) {
+    let config = ModalConfig {
+        title: "Spending Statistics".to_string(),
+        min_width: area.width,
+        min_height: area.height,
+        border_color: Color::Gray,
+        bg_color: Color::Black,
+    };
+
+    render_modal_frame(f, area, &config, |f, inner_area| {
+        let content_chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .constraints(
+                [
+                    Constraint::Length(1),
+                    Constraint::Length(1),
+                    Constraint::Min(10),
+                    Constraint::Length(12),
+                    Constraint::Length(1),
+                ]
+                .as_ref(),
+            )
+            .split(inner_area);
+
+        render_stats_summary(f, content_chunks[0], view_state.stats);
+        render_period_chart(f, content_chunks[2], view_state);
+
+        let bottom_chunks = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+            .split(content_chunks[3]);
+
+        render_top_products(f, bottom_chunks[0], view_state.stats);
+        render_hourly_chart(f, bottom_chunks[1], view_state.stats);
+
+        let help_text = Paragraph::new("'d/w/m' or '←/→': Day/Week/Month | 'esc': Close")
+            .style(Style::default().fg(Color::Gray))
+            .alignment(Alignment::Center);
+        f.render_widget(help_text, content_chunks[4]);
+    });
+}
+
+fn render_stats_summary(f: &mut Frame, area: Rect, stats: &SpendingStats) {
+    let label = Style::default()
+        .fg(Color::Gray)
+        .add_modifier(Modifier::BOLD);
+    let value = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let mut spans = vec![
+        Span::styled(" Total: ", label),
+        Span::styled(stats.total.to_string(), value),
+        Span::styled("   Purchases: ", label),
+        Span::styled(stats.count.to_string(), value),
+        Span::styled("   Average price: ", label),
+        Span::styled(stats.average.to_string(), value),
+    ];
+
+    if let Some(product) = stats.top_products.first() {
+        spans.push(Span::styled("   Most bought: ", label));
+        spans.push(Span::styled(product.name.clone(), value));
+    }
+
+    f.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+fn render_period_chart(f: &mut Frame, area: Rect, view_state: &StatsViewState) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!(" Spent per {} ", view_state.period.label()));
+
+    let periods = &view_state.stats.periods;
+    let inner_width = block.inner(area).width as usize;
+    let bar_width = (inner_width.saturating_sub(periods.len()) / periods.len().max(1)).clamp(3, 9);
+
+    let bars: Vec<Bar> = periods
+        .iter()
+        .map(|(label, amount)| {
+            Bar::default()
+                .value(amount.as_cents().max(0) as u64)
+                .text_value(amount.kroner().to_string())
+                .label(Line::from(label.clone()))
+        })
+        .collect();
+
+    let chart = BarChart::default()
+        .block(block)
+        .data(BarGroup::default().bars(&bars))
+        .bar_width(bar_width as u16)
+        .bar_gap(1)
+        .bar_style(Style::default().fg(Color::Yellow))
+        .value_style(
+            Style::default()
+                .fg(Color::Black)
+                .bg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        );
+
+    f.render_widget(chart, area);
+}
+
+fn render_top_products(f: &mut Frame, area: Rect, stats: &SpendingStats) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Most bought ");
+
+    if stats.top_products.is_empty() {
+        f.render_widget(
+            Paragraph::new(" No purchases yet")
+                .style(Style::default().fg(Color::DarkGray))
+                .block(block),
+            area,
+        );
+        return;
+    }
+
+    let width = block.inner(area).width as usize;
+    let price_width = 14;
+    let name_width = width.saturating_sub(price_width + 8);
+
+    let lines: Vec<Line> = stats
+        .top_products
+        .iter()
+        .map(|product| {
+            Line::from(vec![
+                Span::styled(
+                    format!(" {:>4}× ", product.count),
+                    Style::default().fg(Color::Cyan),
+                ),
+                Span::raw(format!(
+                    "{:<name_width$}",
+                    truncate_text(&product.name, name_width)
+                )),
+                Span::styled(
+                    format!("{:>price_width$}", product.total.to_string()),
+                    Style::default().fg(Color::Yellow),
+                ),
+            ])
+        })
+        .collect();
+
+    f.render_widget(Paragraph::new(lines).block(block), area);
+}
+
+fn render_hourly_chart(f: &mut Frame, area: Rect, stats: &SpendingStats) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(" Purchases by time of day ");
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)].as_ref())
+        .split(inner_area);
+
+    let column_width = (inner_area.width as usize / stats.hourly.len()).max(1);
+    let data: Vec<u64> = stats
+        .hourly
+        .iter()
+        .flat_map(|count| std::iter::repeat_n(*count, column_width))
+        .collect();
+
+    let sparkline = Sparkline::default()
+        .data(&data)
+        .style(Style::default().fg(Color::Cyan));
+    f.render_widget(sparkline, chunks[0]);
+
+    let labels: String = (0..stats.hourly.len())
+        .step_by(3)
+        .map(|hour| format!("{hour:<width$}", width = column_width * 3))
+        .collect();
+
+    f.render_widget(
+        Paragraph::new(labels).style(Style::default().fg(Color::Gray)),
+        chunks[1],
+    );
+}
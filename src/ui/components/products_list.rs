@@ -8,12 +8,16 @@ use ratatui::{
     Frame,
 };
 
+use crate::app::modals::cart::CartModalActions;
 use crate::app::state::AppState;
+use crate::app::tasks::TaskKind;
 use crate::models::product::Product;
 use crate::ui::components::helpers::error::render_error;
 use crate::ui::components::helpers::layout::{
     calculate_product_column_layout, truncate_with_ellipsis, ColumnLayoutConfig,
 };
+use crate::ui::components::helpers::spinner::spinner_frame;
+use crate::utils::formatters::format_stale_since;
 
 pub fn render_products(
     f: &mut Frame,
@@ -23,9 +27,37 @@ pub fn render_products(
     list_state: &ListState,
     app_state: &AppState,
 ) {
+    let loading = app_state
+        .tasks
+        .is_running(TaskKind::Products)
+        .then(|| spinner_frame(app_state.tasks.spinner_frame));
+
+    let title = if app_state.cart.lines.is_empty() {
+        " Products ".to_string()
+    } else {
+        let item_count: u32 = app_state.cart.lines.iter().map(|line| line.quantity).sum();
+        format!(
+            " Products | Cart: {item_count} item{} ({}) ",
+            if item_count == 1 { "" } else { "s" },
+            app_state.cart_total()
+        )
+    };
+
+    let title = match app_state.products.updated_at {
+        Some(updated_at) if app_state.products.stale => {
+            format!("{title}({}) ", format_stale_since(updated_at))
+        }
+        _ => title,
+    };
+
+    let title = match loading {
+        Some(spinner) => format!("{title}{spinner} "),
+        None => title,
+    };
+
     let products_block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(" {} ", "Products"))
+        .title(title)
         .title_style(Style::default().add_modifier(Modifier::BOLD))
         .padding(ratatui::widgets::Padding {
             left: 1,
@@ -34,7 +66,12 @@ pub fn render_products(
             bottom: 0,
         });
 
-    if let Some(ref error) = error {
+    if let (Some(spinner), true) = (loading, products.is_empty()) {
+        let loading_text = Paragraph::new(format!("{spinner} Loading products…"))
+            .style(Style::default().fg(Color::Yellow))
+            .block(products_block);
+        f.render_widget(loading_text, area);
+    } else if let Some(ref error) = error {
         let inner_area = products_block.inner(area);
         f.render_widget(products_block, area);
         render_error(f, inner_area, error, Some("Error loading products"));
@@ -44,15 +81,10 @@ pub fn render_products(
             .block(products_block);
         f.render_widget(empty_text, area);
     } else {
-        let mut products_vec: Vec<&Product> = products.values().collect();
-
-        products_vec.sort_by(|a, b| match (a.id.parse::<i32>(), b.id.parse::<i32>()) {
-            (Ok(id_a), Ok(id_b)) => id_a.cmp(&id_b),
-            _ => a.id.cmp(&b.id),
-        });
+        let products_vec = app_state.get_sorted_products();
 
         let content_width = area.width.saturating_sub(4);
-        let main_content_width = content_width.saturating_sub(4);
+        let main_content_width = content_width.saturating_sub(6);
 
         let layout = calculate_product_column_layout(
             &products_vec,
@@ -106,8 +138,15 @@ pub fn render_products(
                     (Style::default(), Style::default().fg(Color::Gray), false)
                 };
 
+                let favorite_marker = if app_state.config.is_favorite(&product.id) {
+                    Span::styled("★ ", content_style.fg(Color::Yellow))
+                } else {
+                    Span::styled("  ", content_style)
+                };
+
                 let line = Line::from(vec![
                     Span::styled(format!("{relative_line:>3} "), line_number_style),
+                    favorite_marker,
                     Span::styled(id_formatted, content_style),
                     Span::styled(
                         name_with_space,
@@ -9,12 +9,24 @@ use ratatui::{
 use crate::app::state::UserState;
 use crate::models::member::MemberInfo;
 use crate::models::sale::Sale;
-use crate::utils::formatters::truncate_text;
+use crate::utils::formatters::{format_stale_since, truncate_text};
+
+pub fn render_user_panel(f: &mut Frame, area: Rect, user_state: &UserState, loading: Option<&str>) {
+    let title = match user_state.updated_at {
+        Some(updated_at) if user_state.stale => {
+            format!(" User Info ({}) ", format_stale_since(updated_at))
+        }
+        _ => " User Info ".to_string(),
+    };
+
+    let title = match loading {
+        Some(spinner) => format!("{title}{spinner} "),
+        None => title,
+    };
 
-pub fn render_user_panel(f: &mut Frame, area: Rect, user_state: &UserState) {
     let panel_block = Block::default()
         .borders(Borders::ALL)
-        .title(format!(" {} ", "User Info"))
+        .title(title)
         .style(Style::default().add_modifier(Modifier::BOLD));
 
     let inner_area = panel_block.inner(area);
@@ -72,6 +84,11 @@ pub fn render_user_panel(f: &mut Frame, area: Rect, user_state: &UserState) {
     if let Some(ref member_info) = user_state.member_info {
         render_member_info(f, chunks[0], member_info);
         render_sales(f, chunks[1], &user_state.latest_sales);
+    } else if let Some(spinner) = loading {
+        let loading_text = Paragraph::new(format!("{spinner} Loading user data…"))
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(Alignment::Center);
+        f.render_widget(loading_text, inner_area);
     } else {
         let no_user_text = Paragraph::new("No user information available")
             .style(Style::default().fg(Color::Yellow))
@@ -146,7 +163,7 @@ fn render_sales(f: &mut Frame, area: Rect, sales: &[Sale]) {
 
     let max_price_width = sales
         .iter()
-        .map(|s| format!("{},{:02} DKK", s.price.kroner(), s.price.cents()).len())
+        .map(|s| s.price.to_string().len())
         .max()
         .unwrap_or(0);
 
@@ -158,7 +175,7 @@ fn render_sales(f: &mut Frame, area: Rect, sales: &[Sale]) {
     let items: Vec<ListItem> = sales
         .iter()
         .map(|sale| {
-            let full_price = format!("{},{:02} DKK", sale.price.kroner(), sale.price.cents());
+            let full_price = sale.price.to_string();
 
             let date_display = sale.formatted_timestamp();
 
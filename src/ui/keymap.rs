@@ -0,0 +1,281 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::app::action::Action;
+use crate::app::state::{AppState, HistoryField, InputMode};
+use crate::app::stats::StatsPeriod;
+use crate::app::tasks::PurchaseTarget;
+
+pub fn key_to_action(state: &AppState, key: KeyEvent) -> Option<Action> {
+    match state.ui.input_mode() {
+        InputMode::Normal => normal_mode(key),
+        InputMode::Editing => editing_mode(key),
+        InputMode::EditingUsername => username_editing(key),
+        InputMode::BuyConfirmation => buy_confirmation(state, key),
+        InputMode::SearchMode => search_mode(key),
+        InputMode::ErrorModal => Some(Action::CloseErrorModal),
+        InputMode::ParkingModal if state.modals.parking.confirming => {
+            parking_confirmation(state, key)
+        }
+        InputMode::ParkingModal => parking_modal(state, key),
+        InputMode::ParkingLogModal => parking_log_modal(key),
+        InputMode::CartModal => cart_modal(state, key),
+        InputMode::QuickBuyModal => quick_buy_modal(state, key),
+        InputMode::HistoryModal => history_modal(state, key),
+        InputMode::StatsModal => stats_modal(key),
+        InputMode::TerminalSizeModal => match key.code {
+            KeyCode::Char('q') => Some(Action::Quit),
+            _ => None,
+        },
+    }
+}
+
+fn normal_mode(key: KeyEvent) -> Option<Action> {
+    let action = match key.code {
+        KeyCode::Char('q') => Action::Quit,
+        KeyCode::Esc => Action::CancelLoading,
+        KeyCode::Char('j') | KeyCode::Down => Action::MoveDown,
+        KeyCode::Char('k') | KeyCode::Up => Action::MoveUp,
+        KeyCode::Char('g') => Action::PressG,
+        KeyCode::Char('G') => Action::GoToBottom,
+        KeyCode::Char(c) if c.is_ascii_digit() => Action::PushCountDigit(c),
+        KeyCode::Char('r') => Action::Refresh,
+        KeyCode::Char('u') => Action::OpenUsername,
+        KeyCode::Char('/') | KeyCode::Char('s') => Action::OpenSearch,
+        KeyCode::Char('p') => Action::OpenParking,
+        KeyCode::Char('P') => Action::OpenParkingLog,
+        KeyCode::Char('a') => Action::AddSelectedToCart,
+        KeyCode::Char('c') => Action::OpenCart,
+        KeyCode::Char('f') => Action::ToggleFavorite,
+        KeyCode::Char('F') => Action::NextFavorite,
+        KeyCode::Char(':') => Action::OpenQuickBuy,
+        KeyCode::Char('h') => Action::OpenHistory,
+        KeyCode::Char('S') => Action::OpenStats,
+        KeyCode::Enter => Action::OpenPurchase,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn editing_mode(key: KeyEvent) -> Option<Action> {
+    let action = match key.code {
+        KeyCode::Enter => Action::SubmitLogin,
+        KeyCode::Char(c) => Action::LoginInput(c),
+        KeyCode::Backspace => Action::LoginBackspace,
+        KeyCode::Esc => Action::CancelLogin,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn username_editing(key: KeyEvent) -> Option<Action> {
+    let action = match key.code {
+        KeyCode::Enter => Action::SubmitUsername,
+        KeyCode::Char(c) => Action::UsernameInput(c),
+        KeyCode::Backspace => Action::UsernameBackspace,
+        KeyCode::Esc => Action::CloseUsername,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn buy_confirmation(state: &AppState, key: KeyEvent) -> Option<Action> {
+    let modal = &state.modals.purchase;
+
+    if modal.pending {
+        return (key.code == KeyCode::Esc)
+            .then_some(Action::CancelPurchase(PurchaseTarget::Single));
+    }
+
+    if modal.success || modal.error.is_some() {
+        return Some(Action::ClosePurchase);
+    }
+
+    let action = match key.code {
+        KeyCode::Char('y') => Action::ConfirmPurchase,
+        KeyCode::Char('n') | KeyCode::Esc => Action::ClosePurchase,
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right => Action::IncreaseQuantity,
+        KeyCode::Char('-') | KeyCode::Char('_') | KeyCode::Left => Action::DecreaseQuantity,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn cart_modal(state: &AppState, key: KeyEvent) -> Option<Action> {
+    let modal = &state.modals.cart;
+
+    if modal.pending {
+        return (key.code == KeyCode::Esc).then_some(Action::CancelPurchase(PurchaseTarget::Cart));
+    }
+
+    if modal.success || modal.error.is_some() {
+        return Some(Action::CloseCart);
+    }
+
+    let action = match key.code {
+        KeyCode::Char('y') | KeyCode::Enter => Action::BuyCart,
+        KeyCode::Char('j') | KeyCode::Down => Action::NextCartLine,
+        KeyCode::Char('k') | KeyCode::Up => Action::PreviousCartLine,
+        KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Right => Action::IncreaseCartQuantity,
+        KeyCode::Char('-') | KeyCode::Char('_') | KeyCode::Left => Action::DecreaseCartQuantity,
+        KeyCode::Char('d') | KeyCode::Delete | KeyCode::Backspace => Action::RemoveCartLine,
+        KeyCode::Char('n') | KeyCode::Esc | KeyCode::Char('c') => Action::CloseCart,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn quick_buy_modal(state: &AppState, key: KeyEvent) -> Option<Action> {
+    let modal = &state.modals.quick_buy;
+
+    if modal.pending {
+        return (key.code == KeyCode::Esc)
+            .then_some(Action::CancelPurchase(PurchaseTarget::QuickBuy));
+    }
+
+    if modal.success || modal.error.is_some() {
+        return Some(Action::CloseQuickBuy);
+    }
+
+    if modal.confirming {
+        let action = match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => Action::ConfirmQuickBuy,
+            KeyCode::Char('n') => Action::EditQuickBuy,
+            KeyCode::Esc => Action::CloseQuickBuy,
+            _ => return None,
+        };
+        return Some(action);
+    }
+
+    let action = match key.code {
+        KeyCode::Enter => Action::ReviewQuickBuy,
+        KeyCode::Char(c) => Action::QuickBuyInput(c),
+        KeyCode::Backspace if modal.input.is_empty() => Action::CloseQuickBuy,
+        KeyCode::Backspace => Action::QuickBuyBackspace,
+        KeyCode::Esc => Action::CloseQuickBuy,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn search_mode(key: KeyEvent) -> Option<Action> {
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    let action = match key.code {
+        KeyCode::Enter => Action::SelectSearchResult,
+        KeyCode::Char('n') if ctrl => Action::NextSearchResult,
+        KeyCode::Char('p') if ctrl => Action::PreviousSearchResult,
+        KeyCode::Char('a') if ctrl => Action::AddSearchResultToCart,
+        KeyCode::Down => Action::NextSearchResult,
+        KeyCode::Up => Action::PreviousSearchResult,
+        KeyCode::Char(c) => Action::SearchInput(c),
+        KeyCode::Backspace => Action::SearchBackspace,
+        KeyCode::Esc => Action::CloseSearch,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn parking_modal(state: &AppState, key: KeyEvent) -> Option<Action> {
+    if state.modals.parking.editor.is_some() {
+        let action = match key.code {
+            KeyCode::Enter => Action::SaveParkingEntry,
+            KeyCode::Tab => Action::NextParkingField,
+            KeyCode::Char(c) => Action::ParkingInput(c),
+            KeyCode::Backspace => Action::ParkingBackspace,
+            KeyCode::Esc => Action::CancelParkingEdit,
+            _ => return None,
+        };
+        return Some(action);
+    }
+
+    let action = match key.code {
+        KeyCode::Enter => Action::SubmitParking,
+        KeyCode::Tab => Action::NextParkingField,
+        KeyCode::BackTab => Action::PreviousParkingField,
+        KeyCode::Char('j') | KeyCode::Down => Action::NextParkingEntry,
+        KeyCode::Char('k') | KeyCode::Up => Action::PreviousParkingEntry,
+        KeyCode::Char('a') => Action::AddParkingEntry,
+        KeyCode::Char('e') => Action::EditParkingEntry,
+        KeyCode::Char('d') | KeyCode::Delete => Action::DeleteParkingEntry,
+        KeyCode::Right => Action::NextParkingProfile,
+        KeyCode::Left => Action::PreviousParkingProfile,
+        KeyCode::Esc => Action::CloseParking,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn parking_log_modal(key: KeyEvent) -> Option<Action> {
+    let action = match key.code {
+        KeyCode::Char('j') | KeyCode::Down => Action::NextParkingLogEntry,
+        KeyCode::Char('k') | KeyCode::Up => Action::PreviousParkingLogEntry,
+        KeyCode::Char('P') | KeyCode::Char('q') | KeyCode::Esc => Action::CloseParkingLog,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn history_modal(state: &AppState, key: KeyEvent) -> Option<Action> {
+    if state.modals.history.editing.is_some() {
+        let action = match key.code {
+            KeyCode::Enter | KeyCode::Esc => Action::FinishHistoryEdit,
+            KeyCode::Tab => Action::NextHistoryField,
+            KeyCode::Char(c) => Action::HistoryInput(c),
+            KeyCode::Backspace => Action::HistoryBackspace,
+            _ => return None,
+        };
+        return Some(action);
+    }
+
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+    let action = match key.code {
+        KeyCode::Char('d') if ctrl => Action::PageHistoryDown,
+        KeyCode::Char('u') if ctrl => Action::PageHistoryUp,
+        KeyCode::Char('j') | KeyCode::Down => Action::ScrollHistoryDown,
+        KeyCode::Char('k') | KeyCode::Up => Action::ScrollHistoryUp,
+        KeyCode::PageDown | KeyCode::Char(' ') => Action::PageHistoryDown,
+        KeyCode::PageUp => Action::PageHistoryUp,
+        KeyCode::Char('g') | KeyCode::Home => Action::HistoryTop,
+        KeyCode::Char('G') | KeyCode::End => Action::HistoryBottom,
+        KeyCode::Char('/') => Action::EditHistoryFilter(HistoryField::Product),
+        KeyCode::Char('f') => Action::EditHistoryFilter(HistoryField::From),
+        KeyCode::Char('t') => Action::EditHistoryFilter(HistoryField::To),
+        KeyCode::Tab => Action::NextHistoryField,
+        KeyCode::Char('x') => Action::ClearHistoryFilters,
+        KeyCode::Char('h') | KeyCode::Char('q') | KeyCode::Esc => Action::CloseHistory,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn stats_modal(key: KeyEvent) -> Option<Action> {
+    let action = match key.code {
+        KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => Action::NextStatsPeriod,
+        KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => Action::PreviousStatsPeriod,
+        KeyCode::Char('d') => Action::SetStatsPeriod(StatsPeriod::Day),
+        KeyCode::Char('w') => Action::SetStatsPeriod(StatsPeriod::Week),
+        KeyCode::Char('m') => Action::SetStatsPeriod(StatsPeriod::Month),
+        KeyCode::Char('S') | KeyCode::Char('q') | KeyCode::Esc => Action::CloseStats,
+        _ => return None,
+    };
+    Some(action)
+}
+
+fn parking_confirmation(state: &AppState, key: KeyEvent) -> Option<Action> {
+    let modal = &state.modals.parking;
+
+    if modal.pending {
+        return (key.code == KeyCode::Esc).then_some(Action::CancelParking);
+    }
+
+    if modal.success || modal.error.is_some() {
+        return Some(Action::CloseParking);
+    }
+
+    match key.code {
+        KeyCode::Char('y') => Some(Action::ConfirmParking),
+        KeyCode::Char('n') | KeyCode::Esc => Some(Action::CloseParking),
+        _ => None,
+    }
+}
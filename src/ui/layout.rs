@@ -2,7 +2,7 @@ use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
 pub mod layout_constants {
     pub const HEADER_HEIGHT: u16 = 3;
-    pub const INSTRUCTIONS_HEIGHT: u16 = 3;
+    pub const INSTRUCTIONS_HEIGHT: u16 = 4;
     pub const MIN_CONTENT_HEIGHT: u16 = 10;
     pub const MIN_PRODUCT_WIDTH: u16 = 50;
     pub const USER_PANEL_WIDTH: u16 = 54;
@@ -1,4 +1,5 @@
 pub mod components;
 pub mod events;
+pub mod keymap;
 pub mod layout;
 pub mod render;
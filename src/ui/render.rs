@@ -1,9 +1,22 @@
+use chrono::Local;
 use ratatui::{layout::Rect, Frame};
 
 use crate::app::state::{AppState, InputMode};
+use crate::app::tasks::TaskKind;
 
+use crate::app::history::group_sales_by_day;
+use crate::app::modals::cart::CartModalActions;
+use crate::app::modals::history::HistoryModalActions;
+use crate::app::modals::parking::ParkingModalActions;
+use crate::app::modals::parking_log::ParkingLogModalActions;
+use crate::app::modals::quick_buy::QuickBuyModalActions;
+use crate::app::stats::spending_stats;
 use crate::ui::components::header;
-use crate::ui::components::modals::{error, parking, purchase, search, terminal_size, username};
+use crate::ui::components::helpers::spinner::spinner_frame;
+use crate::ui::components::modals::{
+    cart, dry_run, error, history, parking, parking_log, purchase, quick_buy, search, stats,
+    terminal_size, username,
+};
 use crate::ui::components::products_list::render_products;
 use crate::ui::components::user_panel::render_user_panel;
 use crate::ui::layout;
@@ -15,25 +28,28 @@ pub struct InstructionsViewState<'a> {
 }
 
 pub fn ui(f: &mut Frame, app: &AppState) {
+    let spinner = spinner_frame(app.tasks.spinner_frame);
+    let input_mode = app.ui.input_mode();
+
     if app.modals.terminal_size.visible {
         let size = f.area();
-        terminal_size::render_terminal_size_modal(
-            f,
-            size,
-            &app.ui.input_mode,
-            size.width,
-            size.height,
-        );
+        terminal_size::render_terminal_size_modal(f, size, &input_mode, size.width, size.height);
         return;
     }
 
     let main_chunks = layout::create_main_layout(f.area());
     let top_chunks = layout::create_top_layout(main_chunks[0]);
 
-    header::render_title(f, top_chunks[0]);
-    header::render_username(f, top_chunks[1], app.config.username.as_ref());
+    header::render_title(
+        f,
+        top_chunks[0],
+        app.config.demo,
+        app.config.dry_run(),
+        app.active_parking(Local::now()),
+    );
+    header::render_username(f, top_chunks[1], app.config.username());
 
-    if app.config.username.is_some() {
+    if app.config.username().is_some() {
         render_logged_in_content(f, main_chunks[1], app);
     } else {
         render_welcome_screen(f, main_chunks[1]);
@@ -43,66 +59,177 @@ pub fn ui(f: &mut Frame, app: &AppState) {
         f,
         main_chunks[2],
         &InstructionsViewState {
-            username: app.config.username.as_ref(),
+            username: app.config.username(),
             input: &app.ui.input,
-            input_mode: &app.ui.input_mode,
+            input_mode: &input_mode,
         },
     );
 
-    if app.modals.username.visible {
-        username::render_username_modal(
-            f,
-            f.area(),
-            &app.modals.username.input,
-            &app.ui.input_mode,
-        );
+    for mode in &app.ui.modal_stack {
+        render_modal(f, app, *mode, &input_mode, spinner);
     }
 
-    if app.modals.purchase.visible {
-        let product_details = app
-            .modals
-            .purchase
-            .selected_product_id
-            .as_ref()
-            .and_then(|id| app.products.items.get(id).map(|product| (id, product)));
+    if let Some(request) = app.dry_run_request() {
+        dry_run::render_dry_run_panel(f, f.area(), &request);
+    }
+}
+
+fn render_modal(
+    f: &mut Frame,
+    app: &AppState,
+    mode: InputMode,
+    input_mode: &InputMode,
+    spinner: &str,
+) {
+    match mode {
+        InputMode::EditingUsername => {
+            username::render_username_modal(f, f.area(), &app.modals.username.input, input_mode);
+        }
+        InputMode::BuyConfirmation => {
+            let product_details = app
+                .modals
+                .purchase
+                .selected_product_id
+                .as_ref()
+                .and_then(|id| app.products.items.get(id).map(|product| (id, product)));
 
-        if let Some((product_id, product)) = product_details {
-            purchase::render_buy_modal(
+            if let Some((product_id, product)) = product_details {
+                purchase::render_buy_modal(
+                    f,
+                    f.area(),
+                    &purchase::PurchaseViewState {
+                        product_id,
+                        product,
+                        quantity: app.modals.purchase.quantity,
+                        current_balance: app.user.member_info.as_ref().map(|info| info.balance),
+                        error: app.modals.purchase.error.as_ref(),
+                        success: app.modals.purchase.success,
+                        response: app.modals.purchase.response.as_ref(),
+                        pending: app.modals.purchase.pending.then_some(spinner),
+                    },
+                );
+            }
+        }
+        InputMode::CartModal => {
+            cart::render_cart_modal(
                 f,
                 f.area(),
-                product_id,
-                product,
-                app.modals.purchase.quantity,
-                app.user.member_info.as_ref().map(|info| info.balance),
-                app.modals.purchase.error.as_ref(),
-                app.modals.purchase.success,
+                &cart::CartViewState {
+                    lines: app
+                        .cart
+                        .lines
+                        .iter()
+                        .map(|line| (line, app.products.items.get(&line.product_id)))
+                        .collect(),
+                    selected_index: app.modals.cart.selected_index,
+                    total: app.cart_total(),
+                    current_balance: app.user.member_info.as_ref().map(|info| info.balance),
+                    error: app.modals.cart.error.as_ref(),
+                    success: app.modals.cart.success,
+                    pending: app.modals.cart.pending.then_some(spinner),
+                },
             );
         }
-    }
+        InputMode::QuickBuyModal => {
+            quick_buy::render_quick_buy_modal(
+                f,
+                f.area(),
+                &quick_buy::QuickBuyViewState {
+                    input: &app.modals.quick_buy.input,
+                    lines: &app.modals.quick_buy.lines,
+                    parse_error: app.modals.quick_buy.parse_error.as_ref(),
+                    total: app.quick_buy_total(),
+                    current_balance: app.user.member_info.as_ref().map(|info| info.balance),
+                    confirming: app.modals.quick_buy.confirming,
+                    error: app.modals.quick_buy.error.as_ref(),
+                    success: app.modals.quick_buy.success,
+                    pending: app.modals.quick_buy.pending.then_some(spinner),
+                },
+            );
+        }
+        InputMode::SearchMode => {
+            search::render_search_modal(
+                f,
+                f.area(),
+                &app.modals.search.input,
+                &app.modals.search.results,
+                app.modals.search.selected_index,
+                input_mode,
+            );
+        }
+        InputMode::ParkingModal => {
+            let profiles = app.config.parking_profiles();
+            let profile = app.parking_profile();
 
-    if app.modals.search.visible {
-        search::render_search_modal(
-            f,
-            f.area(),
-            &app.modals.search.input,
-            &app.modals.search.results,
-            app.modals.search.selected_index,
-            &app.ui.input_mode,
-        );
-    }
+            parking::render_parking_modal(
+                f,
+                f.area(),
+                &parking::ParkingViewState {
+                    parking: &app.modals.parking,
+                    profile: &profile,
+                    profile_count: profiles.len(),
+                    phone_numbers: &app.config.phone_numbers,
+                    vehicles: &app.config.vehicles,
+                    active: app.active_parking(Local::now()),
+                    spinner,
+                },
+                input_mode,
+            );
+        }
+        InputMode::ParkingLogModal => {
+            parking_log::render_parking_log_modal(
+                f,
+                f.area(),
+                &parking_log::ParkingLogViewState {
+                    entries: &app.parking_log,
+                    selected_index: app.modals.parking_log.selected_index,
+                    now: Local::now(),
+                },
+            );
+        }
+        InputMode::HistoryModal => {
+            let filter = app.history_filter();
+            let sales = app.ledger_sales();
+            let days = group_sales_by_day(&sales, &filter);
 
-    if app.modals.parking.visible {
-        parking::render_parking_modal(f, f.area(), &app.modals.parking, &app.ui.input_mode);
-    }
+            history::render_history_modal(
+                f,
+                f.area(),
+                &history::HistoryViewState {
+                    history: &app.modals.history,
+                    days: &days,
+                    from: filter.from,
+                    to: filter.to,
+                    invalid_date: filter.invalid_date,
+                },
+            );
+        }
+        InputMode::StatsModal => {
+            let period = app.modals.stats.period;
+            let stats = spending_stats(&app.ledger_sales(), period, Local::now().date_naive());
 
-    if app.modals.error.visible && app.modals.error.message.is_some() {
-        error::render_error_modal(
-            f,
-            f.area(),
-            app.modals.error.message.as_ref().unwrap(),
-            app.modals.error.title.as_deref(),
-            &app.ui.input_mode,
-        );
+            stats::render_stats_modal(
+                f,
+                f.area(),
+                &stats::StatsViewState {
+                    stats: &stats,
+                    period,
+                },
+            );
+        }
+        InputMode::ErrorModal => {
+            if let Some(message) = &app.modals.error.message {
+                error::render_error_modal(
+                    f,
+                    f.area(),
+                    message,
+                    app.modals.error.title.as_deref(),
+                    app.modals.error.success,
+                    input_mode,
+                );
+            }
+        }
+        InputMode::Normal | InputMode::Editing | InputMode::TerminalSizeModal => {}
     }
 }
 
@@ -118,7 +245,14 @@ fn render_logged_in_content(f: &mut Frame, area: Rect, app: &AppState) {
         app,
     );
 
-    render_user_panel(f, middle_chunks[1], &app.user);
+    render_user_panel(
+        f,
+        middle_chunks[1],
+        &app.user,
+        app.tasks
+            .is_running(TaskKind::User)
+            .then(|| spinner_frame(app.tasks.spinner_frame)),
+    );
 }
 
 fn render_welcome_screen(f: &mut Frame, area: Rect) {
@@ -175,9 +309,10 @@ fn render_login_input(f: &mut Frame, area: Rect, input: &str, input_mode: &Input
 
 fn render_navigation_help(f: &mut Frame, area: Rect) {
     let instructions = ratatui::widgets::Paragraph::new(
-        "'j' or '↓': Down | 'k' or '↑': Up | 'gg': Top | 'G': Bottom | 'enter': Buy | '/' or 's': Search | 'u': Change Username | 'p': Parking | 'q': Quit",
+        "'j/k': Down/Up | 'gg/G': Top/Bottom | 'enter': Buy | 'f/F': Favorite/Next | 'a/c': Add/Open Cart | ':': Quick Buy | 'h/S': History/Stats | '/': Search | 'r': Refresh | 'u': Username | 'p/P': Parking/Log | 'q': Quit",
     )
     .style(ratatui::style::Style::default())
+    .wrap(ratatui::widgets::Wrap { trim: true })
     .block(
         ratatui::widgets::Block::default()
             .borders(ratatui::widgets::Borders::ALL)
@@ -8,6 +8,23 @@ pub fn truncate_text(text: &str, max_length: usize) -> String {
     }
 }
 
+pub fn format_countdown(remaining: chrono::Duration) -> String {
+    let minutes = (remaining.num_seconds() + 59) / 60;
+
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{minutes}m"),
+        (hours, minutes) => format!("{hours}h {minutes:02}m"),
+    }
+}
+
+pub fn format_stale_since(updated_at: chrono::DateTime<chrono::Local>) -> String {
+    if updated_at.date_naive() == chrono::Local::now().date_naive() {
+        format!("stale since {}", updated_at.format("%H:%M"))
+    } else {
+        format!("stale since {}", updated_at.format("%d/%m %H:%M"))
+    }
+}
+
 pub fn format_error_message(message: &str, max_width: usize, max_lines: usize) -> String {
     let words: Vec<&str> = message.split_whitespace().collect();
 
@@ -1,5 +1,6 @@
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::iter::Sum;
 use std::ops::{Add, Div, Mul, Sub};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -10,18 +11,36 @@ impl Money {
         Self(cents)
     }
 
+    pub fn as_cents(&self) -> i32 {
+        self.0
+    }
+
     pub fn kroner(&self) -> i32 {
         self.0 / 100
     }
 
-    pub fn cents(&self) -> i32 {
-        self.0.abs() % 100
+    pub fn ore(&self) -> i32 {
+        self.0 % 100
+    }
+
+    pub fn checked_add(self, other: Money) -> Option<Money> {
+        self.0.checked_add(other.0).map(Money)
+    }
+
+    pub fn checked_mul(self, quantity: u32) -> Option<Money> {
+        i32::try_from(quantity)
+            .ok()
+            .and_then(|quantity| self.0.checked_mul(quantity))
+            .map(Money)
     }
 }
 
 impl fmt::Display for Money {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{},{:02} DKK", self.kroner(), self.cents())
+        let sign = if self.0 < 0 { "-" } else { "" };
+        let amount = self.0.unsigned_abs();
+
+        write!(f, "{sign}{},{:02} DKK", amount / 100, amount % 100)
     }
 }
 
@@ -33,6 +52,12 @@ impl Add for Money {
     }
 }
 
+impl Sum for Money {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Money(0), Add::add)
+    }
+}
+
 impl Sub for Money {
     type Output = Self;
 
@@ -90,3 +115,32 @@ impl From<i32> for Money {
         Money::new(cents)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_kroner_and_ore() {
+        assert_eq!(Money::new(1250).to_string(), "12,50 DKK");
+        assert_eq!(Money::new(5).to_string(), "0,05 DKK");
+        assert_eq!(Money::new(-1250).to_string(), "-12,50 DKK");
+        assert_eq!(Money::new(i32::MIN).to_string(), "-21474836,48 DKK");
+    }
+
+    #[test]
+    fn negative_amounts_below_one_krone_keep_their_sign() {
+        let money = Money::new(-50);
+
+        assert_eq!(money.to_string(), "-0,50 DKK");
+        assert_eq!((money.kroner(), money.ore()), (0, -50));
+    }
+
+    #[test]
+    fn parts_add_up_to_the_amount() {
+        for cents in [-1250, -100, -99, -1, 0, 1, 99, 100, 1250] {
+            let money = Money::new(cents);
+            assert_eq!(money.kroner() * 100 + money.ore(), cents);
+        }
+    }
+}